@@ -0,0 +1,70 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use storyteller::options::Options;
+
+const NAMES: [&str; 8] = ["Alice", "Bob", "Cinderella", "the old king", "Prince Charming", "the stepmother", "Taylor", "the wise owl"];
+
+fn generate_story(num_paragraphs: usize) -> String {
+    (0..num_paragraphs).map(|i| {
+        let name = NAMES[i % NAMES.len()];
+        let other_name = NAMES[(i + 3) % NAMES.len()];
+        format!(
+            "At chapter {}: {} was a curious traveller. {} felt as happy as {}, and the sun was shining. \
+            \"What a day,\" {} said cheerfully. If {} was better than {}, then {} went to chapter {}. \
+            The road wound through the quiet hills while the birds sang. {} felt as sad as a lonely stone.\n",
+            i, name, name, other_name, name, name, other_name, name, (i + 1) % num_paragraphs, other_name
+        )
+    }).collect()
+}
+
+fn generate_short_story(num_paragraphs: usize) -> String {
+    (0..num_paragraphs).map(|i| format!("{} was {}.\n", NAMES[i % NAMES.len()], i)).collect()
+}
+
+fn available_job_counts() -> Vec<usize> {
+    let available_jobs = std::thread::available_parallelism().map_or(1, |jobs| jobs.get());
+    let mut job_counts = vec![1, available_jobs];
+    job_counts.dedup();
+    job_counts
+}
+
+fn parse_story_benchmark(c: &mut Criterion) {
+    let story = generate_story(500);
+    let mut group = c.benchmark_group("parser");
+    group.throughput(Throughput::Bytes(story.len() as u64));
+    group.sample_size(10);
+    group.bench_function("parse 500 paragraphs", |b| b.iter(|| {
+        assert!(storyteller::parse_story(story.clone(), &Options::default()).is_ok());
+    }));
+    group.finish();
+}
+
+fn parallel_parse_story_benchmark(c: &mut Criterion) {
+    let story = generate_story(10000);
+    let mut group = c.benchmark_group("parallel parser");
+    group.throughput(Throughput::Bytes(story.len() as u64));
+    group.sample_size(10);
+    for jobs in available_job_counts() {
+        let options = Options { jobs: Some(jobs), ..Options::default() };
+        group.bench_with_input(BenchmarkId::new("parse 10000 paragraphs", format!("{} jobs", jobs)), &options, |b, options| b.iter(|| {
+            assert!(storyteller::parse_story(story.clone(), options).is_ok());
+        }));
+    }
+    group.finish();
+}
+
+fn short_paragraphs_parse_story_benchmark(c: &mut Criterion) {
+    let story = generate_short_story(20000);
+    let mut group = c.benchmark_group("short paragraphs parser");
+    group.throughput(Throughput::Bytes(story.len() as u64));
+    group.sample_size(10);
+    for jobs in available_job_counts() {
+        let options = Options { jobs: Some(jobs), ..Options::default() };
+        group.bench_with_input(BenchmarkId::new("parse 20000 one-sentence paragraphs", format!("{} jobs", jobs)), &options, |b, options| b.iter(|| {
+            assert!(storyteller::parse_story(story.clone(), options).is_ok());
+        }));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, parse_story_benchmark, parallel_parse_story_benchmark, short_paragraphs_parse_story_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,108 @@
+use super::{Expression, Instruction, Program};
+use num::BigUint;
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Target {
+    Block(usize),
+    Missing(BigUint),
+    Exit
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EdgeKind {
+    FallThrough,
+    Goto,
+    ConditionalGoto,
+    VariableGoto
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Edge {
+    pub target: Target,
+    pub kind: EdgeKind
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct BasicBlock {
+    pub label: Option<BigUint>,
+    pub start: usize,
+    pub end: usize,
+    pub edges: Vec<Edge>
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ControlFlowGraph {
+    pub blocks: Vec<BasicBlock>,
+    pub label_blocks: HashMap<BigUint, usize>
+}
+
+fn ends_block(instruction: &Instruction) -> bool {
+    match instruction {
+        Instruction::GotoInstruction(_) | Instruction::ExitInstruction => true,
+        Instruction::IfInstruction(_, inner_instruction) => ends_block(inner_instruction),
+        _ => false
+    }
+}
+
+fn jump_edges(instruction: &Instruction, conditional: bool, label_blocks: &HashMap<BigUint, usize>) -> Vec<Edge> {
+    let kind = if conditional { EdgeKind::ConditionalGoto } else { EdgeKind::Goto };
+    match instruction {
+        Instruction::ExitInstruction => vec![Edge { target: Target::Exit, kind }],
+        Instruction::GotoInstruction(Expression::NumberLiteral(label)) => {
+            let target = label_blocks.get(label).map_or(Target::Missing(label.clone()), |block| Target::Block(*block));
+            vec![Edge { target, kind }]
+        }
+        Instruction::GotoInstruction(Expression::Variable(_)) => {
+            let mut blocks = label_blocks.values().copied().collect::<Vec<_>>();
+            blocks.sort();
+            blocks.dedup();
+            blocks.into_iter().map(Target::Block).chain([Target::Exit])
+                .map(|target| Edge { target, kind: EdgeKind::VariableGoto })
+                .collect()
+        }
+        Instruction::IfInstruction(_, inner_instruction) => jump_edges(inner_instruction, true, label_blocks),
+        _ => vec![]
+    }
+}
+
+fn falls_through(instruction: &Instruction) -> bool {
+    !matches!(instruction, Instruction::GotoInstruction(_) | Instruction::ExitInstruction)
+}
+
+impl ControlFlowGraph {
+    pub fn new(program: &Program) -> ControlFlowGraph {
+        let ir = &program.instructions;
+        let mut blocks = vec![];
+        let mut label_blocks = HashMap::new();
+        for (i, instruction) in ir.iter().enumerate() {
+            let starts_block = i == 0 || matches!(instruction, Instruction::Label(_)) || ends_block(&ir[i - 1]);
+            if starts_block {
+                let label = match instruction {
+                    Instruction::Label(label) => Some(label.clone()),
+                    _ => None
+                };
+                if let Some(ref label) = label {
+                    label_blocks.insert(label.clone(), blocks.len());
+                }
+                blocks.push(BasicBlock { label, start: i, end: i + 1, edges: vec![] });
+            } else if let Some(block) = blocks.last_mut() {
+                block.end = i + 1;
+            }
+        }
+        let block_count = blocks.len();
+        for (i, block) in blocks.iter_mut().enumerate() {
+            let last_instruction = &ir[block.end - 1];
+            block.edges = jump_edges(last_instruction, false, &label_blocks);
+            if falls_through(last_instruction) {
+                let target = if i + 1 < block_count { Target::Block(i + 1) } else { Target::Exit };
+                block.edges.push(Edge { target, kind: EdgeKind::FallThrough });
+            }
+        }
+        ControlFlowGraph { blocks, label_blocks }
+    }
+
+    pub fn entry(&self) -> Target {
+        if self.blocks.is_empty() { Target::Exit } else { Target::Block(0) }
+    }
+}
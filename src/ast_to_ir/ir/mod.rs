@@ -0,0 +1,242 @@
+use num::BigUint;
+use std::fmt;
+use std::collections::HashMap;
+use serde::{Serialize, Serializer};
+pub mod cfg;
+pub mod verify;
+
+fn serialize_number<S: Serializer>(number: &BigUint, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&number.to_string())
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+pub enum Expression {
+    NumberLiteral(#[serde(serialize_with = "serialize_number")] BigUint),
+    Variable(String)
+}
+
+// The suffix keeps instructions apart from the statements they are built from.
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub enum Instruction {
+    AssignmentInstruction(Variable, Expression),
+    AddInstruction(Variable, Expression),
+    SubInstruction(Variable, Expression),
+    RandomInstruction(Variable, Expression),
+    PrintNumberInstruction(Variable),
+    PrintStringInstruction(Variable),
+    InputInstruction(Variable),
+    InputNumberInstruction(Variable),
+    InputCharacterInstruction(Variable),
+    ExitInstruction,
+    GotoInstruction(Expression),
+    IfInstruction(Condition, Box<Instruction>),
+    Label(#[serde(serialize_with = "serialize_number")] BigUint)
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+pub struct Variable(pub String);
+
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub enum Condition {
+    EqualTo(Expression, Expression),
+    NotEqualTo(Expression, Expression),
+    GreaterThan(Expression, Expression),
+    LessThan(Expression, Expression),
+    EndOfInput
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SourceLocation {
+    Paragraph(usize),
+    Sentence(usize, usize),
+    Line(usize)
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Metadata {
+    pub source_file: Option<String>,
+    pub paragraph_names: HashMap<BigUint, String>
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Program {
+    pub instructions: Vec<Instruction>,
+    pub variables: Vec<Variable>,
+    pub labels: HashMap<BigUint, usize>,
+    pub source_map: Vec<SourceLocation>,
+    pub metadata: Metadata
+}
+
+impl Program {
+    pub fn new(instructions: Vec<Instruction>, variables: Vec<Variable>, source_map: Vec<SourceLocation>, metadata: Metadata) -> Program {
+        let labels = instructions.iter().enumerate()
+            .filter_map(|(i, instruction)| match instruction {
+                Instruction::Label(label) => Some((label.clone(), i)),
+                _ => None
+            })
+            .collect();
+        Program { instructions, variables, labels, source_map, metadata }
+    }
+}
+
+impl Instruction {
+    pub fn variables(&self) -> Vec<&str> {
+        fn expression_variables(expression: &Expression) -> Vec<&str> {
+            match expression {
+                Expression::Variable(name) => vec![name.as_str()],
+                Expression::NumberLiteral(_) => vec![]
+            }
+        }
+        match self {
+            Instruction::AssignmentInstruction(variable, expression)
+            | Instruction::AddInstruction(variable, expression)
+            | Instruction::SubInstruction(variable, expression)
+            | Instruction::RandomInstruction(variable, expression) => {
+                let mut variables = vec![variable.0.as_str()];
+                variables.extend(expression_variables(expression));
+                variables
+            }
+            Instruction::PrintNumberInstruction(variable)
+            | Instruction::PrintStringInstruction(variable)
+            | Instruction::InputInstruction(variable)
+            | Instruction::InputNumberInstruction(variable)
+            | Instruction::InputCharacterInstruction(variable) => vec![variable.0.as_str()],
+            Instruction::GotoInstruction(expression) => expression_variables(expression),
+            Instruction::IfInstruction(condition, instruction) => {
+                let mut variables = match condition {
+                    Condition::EqualTo(lhs, rhs)
+                    | Condition::NotEqualTo(lhs, rhs)
+                    | Condition::GreaterThan(lhs, rhs)
+                    | Condition::LessThan(lhs, rhs) => [expression_variables(lhs), expression_variables(rhs)].concat(),
+                    Condition::EndOfInput => vec![]
+                };
+                variables.extend(instruction.variables());
+                variables
+            }
+            Instruction::ExitInstruction | Instruction::Label(_) => vec![]
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Instruction::AssignmentInstruction(variable, expression) => {
+                write!(f, "{} = {}", variable, expression)
+            }
+            Instruction::AddInstruction(variable, expression) => {
+                write!(f, "{} += {}", variable, expression)
+            }
+            Instruction::SubInstruction(variable, expression) => {
+                write!(f, "{} -= {}", variable, expression)
+            }
+            Instruction::RandomInstruction(variable, expression) => {
+                write!(f, "{} = roll {}", variable, expression)
+            }
+            Instruction::PrintNumberInstruction(variable) => {
+                write!(f, "print {}", variable)
+            }
+            Instruction::PrintStringInstruction(variable) => {
+                write!(f, "printc {}", variable)
+            }
+            Instruction::InputInstruction(variable) => {
+                write!(f, "input {}", variable)
+            }
+            Instruction::InputNumberInstruction(variable) => {
+                write!(f, "inputn {}", variable)
+            }
+            Instruction::InputCharacterInstruction(variable) => {
+                write!(f, "inputc {}", variable)
+            }
+            Instruction::ExitInstruction => {
+                write!(f, "exit")
+            }
+            Instruction::GotoInstruction(expression) => {
+                write!(f, "goto {}", expression)
+            }
+            Instruction::IfInstruction(condition, instruction) => {
+                write!(f, "if {} {}", condition, instruction)
+            }
+            Instruction::Label(label) => {
+                write!(f, "label {}:", label)
+            }
+        }
+    }
+}
+
+pub const KEYWORDS: [&str; 12] = ["label", "var", "print", "printc", "input", "inputn", "inputc", "exit", "goto", "if", "roll", "eof"];
+
+pub fn is_name_character(character: char) -> bool {
+    character.is_alphanumeric() || character == '\'' || character == '-'
+}
+
+fn format_name(name: &str) -> String {
+    let leading_word = name.split(|character: char| !character.is_alphanumeric()).next().unwrap_or_default();
+    let is_bare_word = !name.is_empty()
+        && name.chars().all(is_name_character)
+        && !name.chars().all(|character| character.is_ascii_digit())
+        && !KEYWORDS.contains(&leading_word);
+    if is_bare_word {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name)
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SourceLocation::Paragraph(paragraph) => {
+                write!(f, "the start of paragraph {}", paragraph)
+            }
+            SourceLocation::Sentence(paragraph, sentence) => {
+                write!(f, "sentence {} of paragraph {}", sentence + 1, paragraph)
+            }
+            SourceLocation::Line(line) => {
+                write!(f, "line {}", line)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Variable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", format_name(&self.0))
+    }
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expression::NumberLiteral(number) => {
+                write!(f, "{}", number)
+            }
+            Expression::Variable(variable) => {
+                write!(f, "{}", format_name(variable))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Condition::EqualTo(left, right) => {
+                write!(f, "{} == {}", left, right)
+            }
+            Condition::NotEqualTo(left, right) => {
+                write!(f, "{} != {}", left, right)
+            }
+            Condition::GreaterThan(left, right) => {
+                write!(f, "{} > {}", left, right)
+            }
+            Condition::LessThan(left, right) => {
+                write!(f, "{} < {}", left, right)
+            }
+            Condition::EndOfInput => {
+                write!(f, "eof")
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,57 @@
+use super::{Expression, Instruction, Program, Variable};
+use crate::errors::Error;
+use crate::errors::compiler_errors;
+use num::BigUint;
+use std::collections::{HashMap, HashSet};
+
+fn find_problems(instruction: &Instruction, nested: bool, variables: &HashSet<&Variable>, labels: &HashMap<BigUint, usize>, problems: &mut Vec<String>) {
+    match instruction {
+        Instruction::Label(label) if nested => problems.push(format!("hides label {} inside a condition", label)),
+        Instruction::GotoInstruction(Expression::NumberLiteral(label)) if !labels.contains_key(label) => {
+            problems.push(format!("jumps to label {}, which does not exist", label))
+        }
+        Instruction::IfInstruction(_, inner_instruction) => {
+            find_problems(inner_instruction, true, variables, labels, problems);
+            return;
+        }
+        _ => {}
+    }
+    for name in instruction.variables() {
+        if name.is_empty() {
+            problems.push("uses a character without a name".to_string());
+        } else if !variables.contains(&Variable(name.to_string())) {
+            problems.push(format!("uses \"{}\", who is missing from the declared characters", name));
+        }
+    }
+}
+
+fn describe_location(program: &Program, position: usize) -> String {
+    match program.source_map.get(position) {
+        Some(location) => location.to_string(),
+        None => format!("instruction {}", position)
+    }
+}
+
+pub fn verify(program: &Program) -> Result<(), Vec<Error>> {
+    let variables = program.variables.iter().collect::<HashSet<_>>();
+    let mut first_positions: HashMap<&BigUint, usize> = HashMap::new();
+    let mut errors = vec![];
+    for (position, instruction) in program.instructions.iter().enumerate() {
+        let mut problems = vec![];
+        if let Instruction::Label(label) = instruction {
+            if let Some(first_position) = first_positions.get(label) {
+                problems.push(format!("repeats label {}, which was already placed at {}", label, describe_location(program, *first_position)));
+            } else {
+                first_positions.insert(label, position);
+            }
+        }
+        find_problems(instruction, false, &variables, &program.labels, &mut problems);
+        let location = describe_location(program, position);
+        errors.extend(problems.into_iter().map(|problem| compiler_errors::malformed_ir_error(location.clone(), instruction.to_string(), problem)));
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
@@ -1,80 +1,172 @@
 use crate::parser::ast;
-use std::collections::HashSet;
+use crate::errors::{Error, Warning};
+use crate::errors::compiler_errors;
+use std::collections::{HashSet, HashMap};
 use num::{BigUint, Zero};
-use chumsky::prelude::*;
 pub mod ir;
-mod pronoun_replacer;
+pub mod pronoun_replacer;
 
-pub fn convert_ast_to_ir(ast: ast::Program, variables: &HashSet<ir::Variable>) -> Vec<ir::Instruction> {
-    let processed_ast = pronoun_replacer::replace_pronouns(&ast, &variables);
+pub fn convert_ast_to_ir_with_warnings(ast: ast::Program, variables: &HashSet<ir::Variable>, pronouns: &HashSet<String>) -> Result<(ir::Program, Vec<Warning>), Vec<Error>> {
+    let (processed_ast, warnings) = pronoun_replacer::replace_pronouns(&ast, variables, pronouns)?;
+    let mut errors = vec![];
+    let paragraph_names = get_paragraph_names(&processed_ast, &mut errors);
     let mut ir: Vec<ir::Instruction> = Vec::new();
+    let mut source_map = vec![];
+    let places = Places { names: paragraph_names, count: processed_ast.0.len() };
     processed_ast.0.iter().enumerate().for_each(|(i, block)| {
         ir.push(ir::Instruction::Label(i.into()));
+        source_map.push(ir::SourceLocation::Paragraph(i));
+        let location = describe_paragraph(i, &block.1);
+        let mut sentence = 0;
         block.0.iter().for_each(|statement| {
-            match statement_to_ir(statement, &variables) {
-                Some(instruction) => ir.push(instruction),
-                None => {}
+            match statement_to_ir(statement, variables, &places, &location) {
+                Ok(Some(instruction)) => {
+                    ir.push(instruction);
+                    source_map.push(ir::SourceLocation::Sentence(i, sentence));
+                }
+                Ok(None) => {}
+                Err(error) => errors.push(error)
+            }
+            if !matches!(statement, ast::Statement::PronounDeclaration(_, _)) {
+                sentence += 1;
             }
         })
     });
-    ir
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    let metadata = ir::Metadata {
+        source_file: None,
+        paragraph_names: places.names.into_iter().map(|(name, index)| (index.into(), name)).collect()
+    };
+    let variables = get_declared_variables(&ir, variables);
+    Ok((ir::Program::new(ir, variables, source_map, metadata), warnings))
+}
+
+fn get_declared_variables(ir: &[ir::Instruction], variables: &HashSet<ir::Variable>) -> Vec<ir::Variable> {
+    let mut remaining_variables = variables.clone();
+    let mut declared_variables = ir.iter()
+        .flat_map(ir::Instruction::variables)
+        .filter_map(|name| remaining_variables.take(&ir::Variable(name.to_string())))
+        .collect::<Vec<_>>();
+    let mut unused_variables = remaining_variables.into_iter().collect::<Vec<_>>();
+    unused_variables.sort_by(|a, b| a.0.cmp(&b.0));
+    declared_variables.extend(unused_variables);
+    declared_variables
+}
+
+struct Places {
+    names: HashMap<String, usize>,
+    count: usize
+}
+
+fn describe_paragraph(index: usize, name: &Option<String>) -> String {
+    match name {
+        Some(name) => format!("the paragraph at \"{}\"", name),
+        None => format!("paragraph {}", index)
+    }
+}
+
+fn get_paragraph_names(ast: &ast::Program, errors: &mut Vec<Error>) -> HashMap<String, usize> {
+    let mut paragraph_names = HashMap::new();
+    ast.0.iter().enumerate().for_each(|(i, block)| {
+        if let Some(ref name) = block.1 {
+            if paragraph_names.insert(name.clone(), i).is_some() {
+                errors.push(compiler_errors::duplicate_place_error(name.clone()));
+            }
+        }
+    });
+    paragraph_names
 }
 
-fn statement_to_ir(statement: &ast::Statement, variables: &HashSet<ir::Variable>) -> Option<ir::Instruction> {
+fn statement_to_ir(statement: &ast::Statement, variables: &HashSet<ir::Variable>, places: &Places, location: &str) -> Result<Option<ir::Instruction>, Error> {
     match *statement {
         ast::Statement::AssignmentStatement(ref lhs, ref rhs) => {
-            Some(ir::Instruction::AssignmentInstruction(ir::Variable(lhs.0.clone()), replace_if_poetic_literal(rhs.clone(), variables)))
+            Ok(Some(ir::Instruction::AssignmentInstruction(ir::Variable(lhs.0.clone()), replace_if_poetic_literal(rhs.clone(), variables))))
         }
         ast::Statement::AddStatement(ref lhs, ref rhs) => {
-            Some(ir::Instruction::AddInstruction(ir::Variable(lhs.0.clone()), replace_if_poetic_literal(rhs.clone(), variables)))
+            Ok(Some(ir::Instruction::AddInstruction(ir::Variable(lhs.0.clone()), replace_if_poetic_literal(rhs.clone(), variables))))
         }
         ast::Statement::SubStatement(ref lhs, ref rhs) => {
-            Some(ir::Instruction::SubInstruction(ir::Variable(lhs.0.clone()), replace_if_poetic_literal(rhs.clone(), variables)))
+            Ok(Some(ir::Instruction::SubInstruction(ir::Variable(lhs.0.clone()), replace_if_poetic_literal(rhs.clone(), variables))))
+        }
+        ast::Statement::RandomStatement(ref lhs, ref rhs) => {
+            Ok(Some(ir::Instruction::RandomInstruction(ir::Variable(lhs.0.clone()), replace_if_poetic_literal(rhs.clone(), variables))))
         }
         ast::Statement::PrintNumberStatement(ref variable) => {
-            Some(ir::Instruction::PrintNumberInstruction(ir::Variable(variable.0.clone())))
+            Ok(Some(ir::Instruction::PrintNumberInstruction(ir::Variable(variable.0.clone()))))
         }
         ast::Statement::PrintStringStatement(ref variable) => {
-            Some(ir::Instruction::PrintStringInstruction(ir::Variable(variable.0.clone())))
+            Ok(Some(ir::Instruction::PrintStringInstruction(ir::Variable(variable.0.clone()))))
         }
         ast::Statement::InputStatement(ref variable) => {
-            Some(ir::Instruction::InputInstruction(ir::Variable(variable.0.clone())))
+            Ok(Some(ir::Instruction::InputInstruction(ir::Variable(variable.0.clone()))))
+        }
+        ast::Statement::InputNumberStatement(ref variable) => {
+            Ok(Some(ir::Instruction::InputNumberInstruction(ir::Variable(variable.0.clone()))))
+        }
+        ast::Statement::InputCharacterStatement(ref variable) => {
+            Ok(Some(ir::Instruction::InputCharacterInstruction(ir::Variable(variable.0.clone()))))
         }
         ast::Statement::ExitStatement => {
-            Some(ir::Instruction::ExitInstruction)
+            Ok(Some(ir::Instruction::ExitInstruction))
         }
         ast::Statement::GotoStatement(ref label) => {
-            Some(ir::Instruction::GotoInstruction(replace_if_poetic_literal(label.clone(), variables)))
+            Ok(Some(ir::Instruction::GotoInstruction(resolve_goto_target(label.clone(), variables, places, location)?)))
         }
         ast::Statement::IfStatement(ref condition, ref statement) => {
-            match statement_to_ir(statement, variables) {
+            match statement_to_ir(statement, variables, places, location)? {
                 Some(inner_statement) => {
-                    Some(ir::Instruction::IfInstruction(condition_to_ir(condition, variables), Box::new(inner_statement)))
+                    Ok(Some(ir::Instruction::IfInstruction(condition_to_ir(condition, variables), Box::new(inner_statement))))
                 }
                 None => {
-                    None
+                    Ok(None)
                 }
             }
         }
-        ast::Statement::Comment => {
-            None
+        ast::Statement::PronounDeclaration(_, _) | ast::Statement::Comment => {
+            Ok(None)
         }
     }
 }
 
+// Variables take precedence over paragraph names, so that existing stories keep their meaning.
+// Once a story names any of its paragraphs, every other literal target must be written as a number.
+fn resolve_goto_target(target: ast::VariableOrNumberLiteral, variables: &HashSet<ir::Variable>, places: &Places, location: &str) -> Result<ir::Expression, Error> {
+    let ast::VariableOrNumberLiteral(ref name) = target;
+    if variables.contains(&ir::Variable(name.clone())) {
+        return Ok(ir::Expression::Variable(name.clone()));
+    }
+    match places.names.get(name) {
+        Some(index) => Ok(ir::Expression::NumberLiteral((*index).into())),
+        None if places.names.is_empty() || name.chars().all(|c| c.is_ascii_digit() || c.is_whitespace()) => {
+            let label = convert_poetic_literal_to_integer(name.clone());
+            if label < places.count.into() {
+                Ok(ir::Expression::NumberLiteral(label))
+            } else {
+                Err(compiler_errors::missing_paragraph_error(name.clone(), label.to_string(), places.count, location.to_string()))
+            }
+        }
+        None => Err(compiler_errors::place_not_found_error(name.clone(), location.to_string()))
+    }
+}
+
 fn condition_to_ir(condition: &ast::Condition, variables: &HashSet<ir::Variable>) -> ir::Condition {
     match *condition {
         ast::Condition::EqualTo(ref lhs, ref rhs) => {
-            ir::Condition::EqualTo(replace_if_poetic_literal(lhs.clone(), &variables), replace_if_poetic_literal(rhs.clone(), &variables))
+            ir::Condition::EqualTo(replace_if_poetic_literal(lhs.clone(), variables), replace_if_poetic_literal(rhs.clone(), variables))
         }
         ast::Condition::NotEqualTo(ref lhs, ref rhs) => {
-            ir::Condition::NotEqualTo(replace_if_poetic_literal(lhs.clone(), &variables), replace_if_poetic_literal(rhs.clone(), &variables))
+            ir::Condition::NotEqualTo(replace_if_poetic_literal(lhs.clone(), variables), replace_if_poetic_literal(rhs.clone(), variables))
         }
         ast::Condition::GreaterThan(ref lhs, ref rhs) => {
-            ir::Condition::GreaterThan(replace_if_poetic_literal(lhs.clone(), &variables), replace_if_poetic_literal(rhs.clone(), &variables))
+            ir::Condition::GreaterThan(replace_if_poetic_literal(lhs.clone(), variables), replace_if_poetic_literal(rhs.clone(), variables))
         }
         ast::Condition::LessThan(ref lhs, ref rhs) => {
-            ir::Condition::LessThan(replace_if_poetic_literal(lhs.clone(), &variables), replace_if_poetic_literal(rhs.clone(), &variables))
+            ir::Condition::LessThan(replace_if_poetic_literal(lhs.clone(), variables), replace_if_poetic_literal(rhs.clone(), variables))
+        }
+        ast::Condition::EndOfInput => {
+            ir::Condition::EndOfInput
         }
     }
 }
@@ -93,12 +185,11 @@ fn replace_if_poetic_literal(value: ast::VariableOrNumberLiteral, variables: &Ha
 
 pub fn convert_poetic_literal_to_integer(poetic_literal: String) -> BigUint {
     fn convert_poetic_string_to_digit(poetic_string: String) -> u8 {
-        (poetic_string.len() % 10).try_into().unwrap()
+        (poetic_string.chars().filter(|c| *c != '\'').count() % 10).try_into().unwrap()
     }
     
     let mut result: BigUint = Zero::zero();
-    let digits_radix = 36;
-    let poetic_literal_spaces_split = text::digits::<_, Simple<char>>(digits_radix).padded().repeated().parse(poetic_literal).unwrap();
+    let poetic_literal_spaces_split = poetic_literal.split_whitespace().map(|word| word.to_string()).collect::<Vec<_>>();
 
     for i in 0..poetic_literal_spaces_split.len() {
         let pow = 10_usize.pow((poetic_literal_spaces_split.len() - i - 1) as u32);
@@ -1,127 +1,187 @@
 use crate::parser::ast;
 use crate::compiler_errors;
-use std::collections::HashSet;
+use crate::errors::{Error, Warning, compiler_warnings};
+use std::collections::{HashSet, HashMap};
 use crate::ast_to_ir::ir;
 
-pub fn replace_pronouns(ast: &ast::Program, variables: &HashSet<ir::Variable>) -> ast::Program {
-    fn replace_pronoun_in_var<'a>(variable: ast::Variable, curr: Option<ast::Variable>, pronouns: &'a HashSet<&str>) -> (ast::Variable, Option<ast::Variable>) {
-        match variable {
-            ast::Variable(name) => {
-                if pronouns.contains(&*name.clone()) {
-                    match curr {
-                        Some(ref curr_var) => {
-                            (ast::Variable(curr_var.0.clone()), curr)
-                        }
-                        None => {
-                            compiler_errors::pronoun_no_antecedent_error().display();
-                            (ast::Variable("".to_string()), None)
-                        }
-                    }
-                } else {
-                    (ast::Variable(name.clone()), Some(ast::Variable(name)))
-                }
-            }
-        }
+struct Antecedents<'a> {
+    variables: &'a HashSet<ir::Variable>,
+    pronouns: HashSet<String>,
+    mentions: HashMap<String, usize>,
+    mention_count: usize,
+    paragraph_mentions: HashSet<String>,
+    declarations: HashMap<String, HashSet<String>>,
+    declared_characters: HashSet<String>,
+    warnings: Vec<Warning>,
+    errors: Vec<Error>
+}
+
+impl<'a> Antecedents<'a> {
+    fn mention(&mut self, name: &str) {
+        self.mention_count += 1;
+        self.mentions.insert(name.to_string(), self.mention_count);
+        self.paragraph_mentions.insert(name.to_string());
     }
 
-    fn replace_pronoun_in_var_or_num_literal<'a>(var_or_num: ast::VariableOrNumberLiteral, curr: Option<ast::Variable>, pronouns: &'a HashSet<&str>, variables: &HashSet<ir::Variable>) -> (ast::VariableOrNumberLiteral, Option<ast::Variable>) {
-        match var_or_num {
-            ast::VariableOrNumberLiteral(name) => {
-                if pronouns.contains(&*name) {
-                    match curr {
-                        Some(ref curr_var) => {
-                            (ast::VariableOrNumberLiteral(curr_var.0.clone()), curr)
-                        }
-                        None => {
-                            compiler_errors::pronoun_no_antecedent_error().display();
-                            (ast::VariableOrNumberLiteral("".to_string()), None)
-                        }
-                    }
-                } else {
-                    if variables.contains(&ir::Variable(name.clone())) {
-                        (ast::VariableOrNumberLiteral(name.clone()), Some(ast::Variable(name)))
-                    } else {
-                        (ast::VariableOrNumberLiteral(name.clone()), curr)
-                    }
-                }
-            }
+    fn most_recent<'b>(&self, characters: impl Iterator<Item = &'b String>) -> Option<String> {
+        characters.max_by_key(|character| self.mentions.get(*character)).cloned()
+    }
+
+    fn declare(&mut self, name: &str, pronouns: &[String]) {
+        let words = name.split(' ').collect::<Vec<_>>();
+        let character = (0..words.len())
+            .map(|start| words[start..].join(" "))
+            .find(|suffix| self.variables.contains(&ir::Variable(suffix.clone())))
+            .unwrap_or(name.to_string());
+        for pronoun in pronouns {
+            self.pronouns.insert(pronoun.clone());
+            self.declarations.entry(pronoun.clone()).or_default().insert(character.clone());
         }
+        self.declared_characters.insert(character.clone());
+        self.mention(&character);
     }
 
-    fn replace_pronouns_in_condition<'a>(curr: Option<ast::Variable>, condition: ast::Condition, pronouns: &'a HashSet<&'a str>, variables: &'a HashSet<ir::Variable>) -> (ast::Condition, Option<ast::Variable>) {
-        match condition {
-            ast::Condition::EqualTo(lhs, rhs) => {
-                let (lhs_pronoun_replacement, new_curr_var) = replace_pronoun_in_var_or_num_literal(lhs, curr, &pronouns, variables);
-                let (rhs_pronoun_replacement, new_curr_var) = replace_pronoun_in_var_or_num_literal(rhs, new_curr_var, &pronouns, variables);
-                (ast::Condition::EqualTo(lhs_pronoun_replacement, rhs_pronoun_replacement), new_curr_var)
-            },
-            ast::Condition::NotEqualTo(lhs, rhs) => {
-                let (lhs_pronoun_replacement, new_curr_var) = replace_pronoun_in_var_or_num_literal(lhs, curr, &pronouns, variables);
-                let (rhs_pronoun_replacement, new_curr_var) = replace_pronoun_in_var_or_num_literal(rhs, new_curr_var, &pronouns, variables);
-                (ast::Condition::NotEqualTo(lhs_pronoun_replacement, rhs_pronoun_replacement), new_curr_var)
-            },
-            ast::Condition::GreaterThan(lhs, rhs) => {
-                let (lhs_pronoun_replacement, new_curr_var) = replace_pronoun_in_var_or_num_literal(lhs, curr, &pronouns, variables);
-                let (rhs_pronoun_replacement, new_curr_var) = replace_pronoun_in_var_or_num_literal(rhs, new_curr_var, &pronouns, variables);
-                (ast::Condition::GreaterThan(lhs_pronoun_replacement, rhs_pronoun_replacement), new_curr_var)
+    fn resolve(&mut self, pronoun: &str) -> Option<String> {
+        let antecedent = match self.declarations.get(pronoun) {
+            Some(characters) => {
+                let chosen = self.most_recent(characters.iter().filter(|character| self.mentions.contains_key(*character)))?;
+                let mut rivals = characters.iter()
+                    .filter(|character| **character != chosen && self.paragraph_mentions.contains(*character))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if !rivals.is_empty() && self.paragraph_mentions.contains(&chosen) {
+                    rivals.sort();
+                    self.warnings.push(compiler_warnings::ambiguous_pronoun_warning(pronoun.to_string(), chosen.clone(), rivals));
+                }
+                chosen
             },
-            ast::Condition::LessThan(lhs, rhs) => {
-                let (lhs_pronoun_replacement, new_curr_var) = replace_pronoun_in_var_or_num_literal(lhs, curr, &pronouns, variables);
-                let (rhs_pronoun_replacement, new_curr_var) = replace_pronoun_in_var_or_num_literal(rhs, new_curr_var, &pronouns, variables);
-                (ast::Condition::LessThan(lhs_pronoun_replacement, rhs_pronoun_replacement), new_curr_var)
+            None => self.most_recent(self.mentions.keys().filter(|character| !self.declared_characters.contains(*character)))
+                .or_else(|| self.most_recent(self.mentions.keys()))?
+        };
+        self.mention(&antecedent);
+        Some(antecedent)
+    }
+
+    fn replace_name(&mut self, name: String) -> String {
+        if self.pronouns.contains(&name) {
+            match self.resolve(&name) {
+                Some(antecedent) => antecedent,
+                None => {
+                    self.errors.push(compiler_errors::pronoun_no_antecedent_error());
+                    name
+                }
             }
+        } else {
+            name
         }
     }
+}
 
-    fn replace_pronouns_in_statement<'a>(curr: Option<ast::Variable>, statement: ast::Statement, pronouns: &'a HashSet<&str>, variables: &'a HashSet<ir::Variable>) -> (ast::Statement, Option<ast::Variable>) {
-        match statement {
-            ast::Statement::AssignmentStatement(lhs, rhs) => {
-                let (lhs_pronoun_replacement, new_curr_var) = replace_pronoun_in_var(lhs, curr, &pronouns);
-                let (rhs_pronoun_replacement, new_curr_var) = replace_pronoun_in_var_or_num_literal(rhs, new_curr_var, &pronouns, variables);
-                (ast::Statement::AssignmentStatement(lhs_pronoun_replacement, rhs_pronoun_replacement), new_curr_var)
-            }
-            ast::Statement::AddStatement(lhs, rhs) => {
-                let (lhs_pronoun_replacement, new_curr_var) = replace_pronoun_in_var(lhs, curr, &pronouns);
-                let (rhs_pronoun_replacement, new_curr_var) = replace_pronoun_in_var_or_num_literal(rhs, new_curr_var, &pronouns, variables);
-                (ast::Statement::AddStatement(lhs_pronoun_replacement, rhs_pronoun_replacement), new_curr_var)
-            }
-            ast::Statement::SubStatement(lhs, rhs) => {
-                let (lhs_pronoun_replacement, new_curr_var) = replace_pronoun_in_var(lhs, curr, &pronouns);
-                let (rhs_pronoun_replacement, new_curr_var) = replace_pronoun_in_var_or_num_literal(rhs, new_curr_var, &pronouns, variables);
-                (ast::Statement::SubStatement(lhs_pronoun_replacement, rhs_pronoun_replacement), new_curr_var)
-            }
-            ast::Statement::PrintNumberStatement(var) => {
-                let (pronoun_replacement, new_curr_var) = replace_pronoun_in_var(var, curr, &pronouns);
-                (ast::Statement::PrintNumberStatement(pronoun_replacement), new_curr_var)
-            }
-            ast::Statement::PrintStringStatement(var) => {
-                let (pronoun_replacement, new_curr_var) = replace_pronoun_in_var(var, curr, &pronouns);
-                (ast::Statement::PrintStringStatement(pronoun_replacement), new_curr_var)
-            }
-            ast::Statement::InputStatement(var) => {
-                let (pronoun_replacement, new_curr_var) = replace_pronoun_in_var(var, curr, &pronouns);
-                (ast::Statement::InputStatement(pronoun_replacement), new_curr_var)
-            }
-            ast::Statement::IfStatement(condition, inner_statement) => {
-                let (condition_with_pronoun_replaced, new_curr_var) = replace_pronouns_in_condition(curr, condition, pronouns, variables);
-                let (inner_statement_with_pronoun_replaced, new_curr_var) = replace_pronouns_in_statement(new_curr_var, *inner_statement, pronouns, variables);
-                (ast::Statement::IfStatement(condition_with_pronoun_replaced, Box::new(inner_statement_with_pronoun_replaced)), new_curr_var)
-            }
-            ast::Statement::GotoStatement(var_or_num) => {
-                let (label_with_pronoun_replaced, new_curr_var) = replace_pronoun_in_var_or_num_literal(var_or_num, curr, &pronouns, &variables);
-                (ast::Statement::GotoStatement(label_with_pronoun_replaced), new_curr_var)
-            }
-            _ => (statement, curr)
+fn replace_pronoun_in_var(variable: ast::Variable, antecedents: &mut Antecedents) -> ast::Variable {
+    let name = antecedents.replace_name(variable.0);
+    antecedents.mention(&name);
+    ast::Variable(name)
+}
+
+fn replace_pronoun_in_var_or_num_literal(var_or_num: ast::VariableOrNumberLiteral, antecedents: &mut Antecedents) -> ast::VariableOrNumberLiteral {
+    let name = antecedents.replace_name(var_or_num.0);
+    if antecedents.variables.contains(&ir::Variable(name.clone())) {
+        antecedents.mention(&name);
+    }
+    ast::VariableOrNumberLiteral(name)
+}
+
+fn replace_pronouns_in_condition(condition: ast::Condition, antecedents: &mut Antecedents) -> ast::Condition {
+    match condition {
+        ast::Condition::EqualTo(lhs, rhs) => {
+            let lhs = replace_pronoun_in_var_or_num_literal(lhs, antecedents);
+            ast::Condition::EqualTo(lhs, replace_pronoun_in_var_or_num_literal(rhs, antecedents))
+        },
+        ast::Condition::NotEqualTo(lhs, rhs) => {
+            let lhs = replace_pronoun_in_var_or_num_literal(lhs, antecedents);
+            ast::Condition::NotEqualTo(lhs, replace_pronoun_in_var_or_num_literal(rhs, antecedents))
+        },
+        ast::Condition::GreaterThan(lhs, rhs) => {
+            let lhs = replace_pronoun_in_var_or_num_literal(lhs, antecedents);
+            ast::Condition::GreaterThan(lhs, replace_pronoun_in_var_or_num_literal(rhs, antecedents))
+        },
+        ast::Condition::LessThan(lhs, rhs) => {
+            let lhs = replace_pronoun_in_var_or_num_literal(lhs, antecedents);
+            ast::Condition::LessThan(lhs, replace_pronoun_in_var_or_num_literal(rhs, antecedents))
+        },
+        ast::Condition::EndOfInput => ast::Condition::EndOfInput
+    }
+}
+
+fn replace_pronouns_in_statement(statement: ast::Statement, antecedents: &mut Antecedents) -> ast::Statement {
+    match statement {
+        ast::Statement::AssignmentStatement(lhs, rhs) => {
+            let lhs = replace_pronoun_in_var(lhs, antecedents);
+            ast::Statement::AssignmentStatement(lhs, replace_pronoun_in_var_or_num_literal(rhs, antecedents))
+        }
+        ast::Statement::AddStatement(lhs, rhs) => {
+            let lhs = replace_pronoun_in_var(lhs, antecedents);
+            ast::Statement::AddStatement(lhs, replace_pronoun_in_var_or_num_literal(rhs, antecedents))
+        }
+        ast::Statement::SubStatement(lhs, rhs) => {
+            let lhs = replace_pronoun_in_var(lhs, antecedents);
+            ast::Statement::SubStatement(lhs, replace_pronoun_in_var_or_num_literal(rhs, antecedents))
+        }
+        ast::Statement::RandomStatement(lhs, rhs) => {
+            let lhs = replace_pronoun_in_var(lhs, antecedents);
+            ast::Statement::RandomStatement(lhs, replace_pronoun_in_var_or_num_literal(rhs, antecedents))
         }
+        ast::Statement::PrintNumberStatement(var) => {
+            ast::Statement::PrintNumberStatement(replace_pronoun_in_var(var, antecedents))
+        }
+        ast::Statement::PrintStringStatement(var) => {
+            ast::Statement::PrintStringStatement(replace_pronoun_in_var(var, antecedents))
+        }
+        ast::Statement::InputStatement(var) => {
+            ast::Statement::InputStatement(replace_pronoun_in_var(var, antecedents))
+        }
+        ast::Statement::InputNumberStatement(var) => {
+            ast::Statement::InputNumberStatement(replace_pronoun_in_var(var, antecedents))
+        }
+        ast::Statement::InputCharacterStatement(var) => {
+            ast::Statement::InputCharacterStatement(replace_pronoun_in_var(var, antecedents))
+        }
+        ast::Statement::IfStatement(condition, inner_statement) => {
+            let condition = replace_pronouns_in_condition(condition, antecedents);
+            ast::Statement::IfStatement(condition, Box::new(replace_pronouns_in_statement(*inner_statement, antecedents)))
+        }
+        ast::Statement::GotoStatement(var_or_num) => {
+            ast::Statement::GotoStatement(replace_pronoun_in_var_or_num_literal(var_or_num, antecedents))
+        }
+        ast::Statement::PronounDeclaration(ref character, ref pronouns) => {
+            antecedents.declare(&character.0, pronouns);
+            statement
+        }
+        _ => statement
     }
+}
 
-    let pronouns = HashSet::from(["he", "she", "they", "him", "her", "them", "ze", "hir", "zie", "zir", "xe", "xem", "ve", "ver"]);
-    let mut curr_var: Option<ast::Variable> = None;
-    ast::Program(ast.0.iter().map(|block| {
+pub fn replace_pronouns(ast: &ast::Program, variables: &HashSet<ir::Variable>, pronouns: &HashSet<String>) -> Result<(ast::Program, Vec<Warning>), Vec<Error>> {
+    let mut antecedents = Antecedents {
+        variables,
+        pronouns: pronouns.clone(),
+        mentions: HashMap::new(),
+        mention_count: 0,
+        paragraph_mentions: HashSet::new(),
+        declarations: HashMap::new(),
+        declared_characters: HashSet::new(),
+        warnings: vec![],
+        errors: vec![]
+    };
+    let program = ast::Program(ast.0.iter().map(|block| {
+        antecedents.paragraph_mentions.clear();
         ast::Block(block.0.iter().map(|statement| {
-            let (statement_with_pronoun_replaced, new_curr_var) = replace_pronouns_in_statement(curr_var.clone(), statement.clone(), &pronouns, variables);
-            curr_var = new_curr_var.clone();
-            statement_with_pronoun_replaced
-        }).collect::<Vec<_>>())
-    }).collect::<Vec<_>>())
-}
\ No newline at end of file
+            replace_pronouns_in_statement(statement.clone(), &mut antecedents)
+        }).collect::<Vec<_>>(), block.1.clone())
+    }).collect::<Vec<_>>());
+    if antecedents.errors.is_empty() {
+        Ok((program, antecedents.warnings))
+    } else {
+        Err(antecedents.errors)
+    }
+}
@@ -0,0 +1,103 @@
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use crate::errors::Error;
+use crate::errors::compiler_errors;
+use crate::options::Options;
+
+const CONFIG_FILE_NAME: &str = "storyteller.toml";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    #[serde(default)]
+    pub keywords: KeywordsConfig,
+    pub pronouns: Option<Vec<String>>,
+    #[serde(default)]
+    pub options: Options,
+    #[serde(skip)]
+    pub path: Option<PathBuf>
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KeywordsConfig {
+    pub to_be: Option<KeywordCategoryConfig>,
+    pub positive_adjective: Option<KeywordCategoryConfig>,
+    pub negative_adjective: Option<KeywordCategoryConfig>,
+    pub said: Option<KeywordCategoryConfig>,
+    pub goto: Option<KeywordCategoryConfig>,
+    pub positive_comparative_adjective: Option<KeywordCategoryConfig>,
+    pub negative_comparative_adjective: Option<KeywordCategoryConfig>
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KeywordCategoryConfig {
+    pub replace: Option<Vec<String>>,
+    #[serde(default)]
+    pub extend: Vec<String>
+}
+
+fn find_config_file(story_directory: &Path) -> Option<PathBuf> {
+    let story_directory = if story_directory.as_os_str().is_empty() { PathBuf::from(".") } else { story_directory.to_path_buf() };
+    let story_directory = story_directory.canonicalize().unwrap_or(story_directory);
+    story_directory.ancestors()
+        .map(|directory| directory.join(CONFIG_FILE_NAME))
+        .find(|config_path| config_path.is_file())
+}
+
+fn validate_keyword_list(name: &str, keywords: &[String], config_path: &Path) -> Result<(), Error> {
+    if keywords.iter().any(|keyword| keyword.trim().is_empty()) {
+        return Err(compiler_errors::invalid_config_error(config_path.display().to_string(), format!("\"{}\" contains an empty keyword", name)));
+    }
+    Ok(())
+}
+
+fn validate_keyword_category(name: &str, category: &Option<KeywordCategoryConfig>, config_path: &Path) -> Result<(), Error> {
+    if let Some(category) = category {
+        if let Some(ref replacement) = category.replace {
+            if replacement.is_empty() && category.extend.is_empty() {
+                return Err(compiler_errors::invalid_config_error(config_path.display().to_string(), format!("\"{}\" would be left without any keywords", name)));
+            }
+            validate_keyword_list(name, replacement, config_path)?;
+        }
+        validate_keyword_list(name, &category.extend, config_path)?;
+    }
+    Ok(())
+}
+
+fn validate_config(config: &Config, config_path: &Path) -> Result<(), Error> {
+    let keywords = &config.keywords;
+    validate_keyword_category("to_be", &keywords.to_be, config_path)?;
+    validate_keyword_category("positive_adjective", &keywords.positive_adjective, config_path)?;
+    validate_keyword_category("negative_adjective", &keywords.negative_adjective, config_path)?;
+    validate_keyword_category("said", &keywords.said, config_path)?;
+    validate_keyword_category("goto", &keywords.goto, config_path)?;
+    validate_keyword_category("positive_comparative_adjective", &keywords.positive_comparative_adjective, config_path)?;
+    validate_keyword_category("negative_comparative_adjective", &keywords.negative_comparative_adjective, config_path)?;
+    if let Some(ref pronouns) = config.pronouns {
+        validate_keyword_list("pronouns", pronouns, config_path)?;
+    }
+    Ok(())
+}
+
+pub fn parse_config(contents: &str, config_path: &Path) -> Result<Config, Error> {
+    let mut config: Config = match toml::from_str(contents) {
+        Ok(config) => config,
+        Err(error) => return Err(compiler_errors::invalid_config_error(config_path.display().to_string(), error.message().to_string()))
+    };
+    validate_config(&config, config_path)?;
+    config.path = Some(config_path.to_path_buf());
+    Ok(config)
+}
+
+pub fn get_config(story_directory: &Path) -> Result<Config, Error> {
+    let config_path = match find_config_file(story_directory) {
+        Some(config_path) => config_path,
+        None => return Ok(Config::default())
+    };
+    match std::fs::read_to_string(&config_path) {
+        Ok(contents) => parse_config(&contents, &config_path),
+        Err(_) => Err(compiler_errors::invalid_config_error(config_path.display().to_string(), "the file could not be read".to_string()))
+    }
+}
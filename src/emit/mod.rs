@@ -0,0 +1,111 @@
+use crate::lexer::lexer_types::LexerOutput;
+use crate::parser::ast;
+use crate::ast_to_ir::ir::{self, cfg};
+use crate::errors::{Error, compiler_errors};
+use crate::options::{EmitKind, Options};
+
+pub fn tokens_to_string(lexer_output: &LexerOutput) -> String {
+    lexer_output.0.iter().enumerate()
+        .map(|(i, block)| {
+            let tokens = block.0.iter().map(|token| format!("    {:?}\n", token)).collect::<String>();
+            format!("paragraph {}:\n{}", i, tokens)
+        })
+        .collect()
+}
+
+pub fn ast_to_string(ast: &ast::Program) -> String {
+    format!("{:#?}\n", ast)
+}
+
+pub fn ast_to_json(ast: &ast::Program) -> String {
+    format!("{}\n", serde_json::to_string_pretty(ast).expect("Error serialising the AST"))
+}
+
+pub fn ir_to_string(program: &ir::Program) -> String {
+    let variable_declarations = program.variables.iter().map(|variable| format!("var {}\n", variable)).collect::<Vec<_>>();
+    let instructions = program.instructions.iter()
+        .map(|instruction| match instruction {
+            ir::Instruction::Label(_) => format!("{}\n", instruction),
+            _ => format!("    {}\n", instruction)
+        })
+        .collect::<String>();
+    format!("{}{}", variable_declarations.concat(), instructions)
+}
+
+pub fn ir_to_json(program: &ir::Program) -> String {
+    format!("{}\n", serde_json::to_string_pretty(&program.instructions).expect("Error serialising the IR"))
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn dot_node(target: &cfg::Target) -> String {
+    match target {
+        cfg::Target::Block(block) => format!("block{}", block),
+        cfg::Target::Missing(label) => format!("missing{}", label),
+        cfg::Target::Exit => "exit".to_string()
+    }
+}
+
+pub fn cfg_to_dot(program: &ir::Program) -> String {
+    let graph = cfg::ControlFlowGraph::new(program);
+    let mut dot = String::from("digraph story {\n    node [shape=box, fontname=\"monospace\"];\n");
+    if let Some(ref source_file) = program.metadata.source_file {
+        dot.push_str(&format!("    label=\"{}\";\n    labelloc=t;\n", escape_dot(source_file)));
+    }
+    dot.push_str("    start [shape=circle, label=\"\"];\n    exit [shape=doublecircle, label=\"the end\"];\n");
+    dot.push_str(&format!("    start -> {};\n", dot_node(&graph.entry())));
+    for (i, block) in graph.blocks.iter().enumerate() {
+        let lines = program.instructions[block.start..block.end].iter()
+            .map(|instruction| match instruction {
+                ir::Instruction::Label(label) if program.metadata.paragraph_names.contains_key(label) => {
+                    format!("{} # {}", instruction, program.metadata.paragraph_names[label])
+                }
+                _ => instruction.to_string()
+            })
+            .map(|line| format!("{}\\l", escape_dot(&line)))
+            .collect::<String>();
+        dot.push_str(&format!("    block{} [label=\"{}\"];\n", i, lines));
+    }
+    let mut missing_labels = graph.blocks.iter()
+        .flat_map(|block| &block.edges)
+        .filter_map(|edge| match edge.target {
+            cfg::Target::Missing(ref label) => Some(label),
+            _ => None
+        })
+        .collect::<Vec<_>>();
+    missing_labels.sort();
+    missing_labels.dedup();
+    for label in missing_labels {
+        dot.push_str(&format!("    missing{} [shape=octagon, color=red, label=\"no paragraph {}\"];\n", label, label));
+    }
+    for (i, block) in graph.blocks.iter().enumerate() {
+        for edge in &block.edges {
+            let style = match edge.kind {
+                cfg::EdgeKind::FallThrough => "",
+                cfg::EdgeKind::Goto => " [style=bold]",
+                cfg::EdgeKind::ConditionalGoto => " [style=dashed]",
+                cfg::EdgeKind::VariableGoto => " [style=dotted]"
+            };
+            dot.push_str(&format!("    block{} -> {}{};\n", i, dot_node(&edge.target), style));
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+pub fn emit(options: &Options, kind: EmitKind, get_contents: impl FnOnce() -> String) -> Result<(), Error> {
+    let emits = options.emit.iter().filter(|emit| emit.kind == kind).collect::<Vec<_>>();
+    if emits.is_empty() {
+        return Ok(());
+    }
+    let contents = get_contents();
+    for emit in emits {
+        match emit.path {
+            Some(ref path) => std::fs::write(path, &contents).map_err(|_| compiler_errors::output_file_error(path.clone()))?,
+            None => print!("{}", contents)
+        }
+    }
+    Ok(())
+}
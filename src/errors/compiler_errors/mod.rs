@@ -14,6 +14,13 @@ pub fn file_not_found_error() -> Error {
     }
 }
 
+pub fn output_file_error(file: String) -> Error {
+    Error {
+        error_type: "Unwritten Chapter Error".to_owned(),
+        error_message: format!("The storyteller sat down to write \"{}\", but the page would not take the ink. Make sure its folder exists and that you are allowed to write there.", file)
+    }
+}
+
 pub fn illegal_argument_error() -> Error {
     Error {
         error_type: "Enigmatic Whispers Error".to_owned(),
@@ -26,4 +33,115 @@ pub fn pronoun_no_antecedent_error() -> Error {
         error_type: "Lonely Pronoun Error".to_owned(),
         error_message: "Oh, the tragedy that has befallen us! A forlorn pronoun meanders aimlessly, searching for its lost noun companion. Alas, it finds itself adrift in a sea of ambiguity, yearning for connection.".to_owned()
     }
-}
\ No newline at end of file
+}
+pub fn place_not_found_error(place: String, location: String) -> Error {
+    Error {
+        error_type: "Place Not Found Error".to_owned(),
+        error_message: format!("In {}, your characters pack their bags for \"{}\", yet no chapter of your story bears that name. Name the paragraph with an opener like \"At {}:\", or send them somewhere that exists.", location, place, place)
+    }
+}
+
+pub fn missing_paragraph_error(place: String, paragraph: String, paragraph_count: usize, location: String) -> Error {
+    let (destination, hint) = if place == paragraph {
+        (format!("paragraph {}", paragraph), String::new())
+    } else {
+        (format!("\"{}\", which is paragraph {}", place, paragraph), format!(" If \"{}\" is meant to be the name of a place, give a paragraph an opener like \"At {}:\".", place, place))
+    };
+    let ending = match paragraph_count.checked_sub(1) {
+        Some(last_paragraph) => format!("the story ends with paragraph {}", last_paragraph),
+        None => "the story has no paragraphs at all".to_string()
+    };
+    Error {
+        error_type: "Place Not Found Error".to_owned(),
+        error_message: format!("In {}, your characters set off for {}, but {}. Paragraphs are counted from 0, so send them somewhere that exists.{}", location, destination, ending, hint)
+    }
+}
+
+pub fn duplicate_place_error(place: String) -> Error {
+    Error {
+        error_type: "Twin Towns Error".to_owned(),
+        error_message: format!("Two chapters of your story both claim to take place at \"{}\". A wandering character would never know which one to visit. Give each place a name of its own.", place)
+    }
+}
+
+pub fn keyword_directory_not_found_error(directory: String) -> Error {
+    Error {
+        error_type: "Lost Library Error".to_owned(),
+        error_message: format!("You were promised a library of words at \"{}\", but when you arrived, only an empty field remained. Check the paths listed in STORYTELLER_KEYWORDS_PATH.", directory)
+    }
+}
+
+pub fn keyword_file_unreadable_error(file: String) -> Error {
+    Error {
+        error_type: "Sealed Tome Error".to_owned(),
+        error_message: format!("The dictionary \"{}\" sits on the shelf, but its pages are glued shut. Make sure the file can be read.", file)
+    }
+}
+
+pub fn invalid_config_error(config_file: String, problem: String) -> Error {
+    Error {
+        error_type: "Garbled Manuscript Error".to_owned(),
+        error_message: format!("The editor's notes in \"{}\" are smudged beyond recognition: {}. Revise them before the story goes to print.", config_file, problem)
+    }
+}
+
+pub fn unknown_language_error(language: String) -> Error {
+    Error {
+        error_type: "Foreign Tongue Error".to_owned(),
+        error_message: format!("Your narrator clears their throat to tell the tale in {}, but it is a tongue no bard in this land has ever learned. Choose a language pack the storyteller knows, such as english or spanish.", language)
+    }
+}
+
+pub fn blank_keyword_error(location: String) -> Error {
+    Error {
+        error_type: "Blank Page Error".to_owned(),
+        error_message: format!("At {} the dictionary holds an empty line, a word written in invisible ink. No reader could ever speak it. Remove the blank line.", location)
+    }
+}
+
+pub fn keyword_conflict_error(keyword: String, first_use: String, second_use: String) -> Error {
+    Error {
+        error_type: "Double Meaning Error".to_owned(),
+        error_message: format!("The word \"{}\" leads a double life: it is listed under {} and again under {}. Your readers cannot know which meaning you intend. Keep it in only one dictionary.", keyword, first_use, second_use)
+    }
+}
+
+pub fn unclear_condition_error(sentence: String, condition: String, trouble: String) -> Error {
+    let trouble = if trouble.is_empty() {
+        "it ends before anything is compared".to_string()
+    } else {
+        format!("the trouble starts at \"{}\"", trouble)
+    };
+    Error {
+        error_type: "Riddle Without Answer Error".to_owned(),
+        error_message: format!("In the sentence \"{}\", the narrator ponders whether \"{}\", but nobody can tell what that would mean, as {}. Compare two things, as in \"If Alice was 3, then...\" or \"If Alice was better than Bob, then...\".", sentence, condition, trouble)
+    }
+}
+
+pub fn formatting_changed_meaning_error(file: String) -> Error {
+    Error {
+        error_type: "Unfaithful Retelling Error".to_owned(),
+        error_message: format!("The storyteller tried to tidy up \"{}\", but the tidied tale no longer means what the original did. The file has been left exactly as it was.", file)
+    }
+}
+
+pub fn ir_syntax_error(line: usize, found: String) -> Error {
+    Error {
+        error_type: "Garbled Manuscript Error".to_owned(),
+        error_message: format!("Line {} of the manuscript makes no sense to the storyteller, who stumbled upon {}. Every line holds a single label, variable declaration or instruction.", line, found)
+    }
+}
+
+pub fn undeclared_variable_error(variable: String) -> Error {
+    Error {
+        error_type: "Uninvited Guest Error".to_owned(),
+        error_message: format!("\"{}\" turns up in the manuscript, but nobody ever introduced them. Declare them with \"var {}\" before the story begins.", variable, variable)
+    }
+}
+
+pub fn malformed_ir_error(location: String, instruction: String, problem: String) -> Error {
+    Error {
+        error_type: "Torn Page Error".to_owned(),
+        error_message: format!("At {} of the manuscript, the instruction \"{}\" {}. The story cannot be bound with a torn page; if the manuscript was not written by hand, this is a bug in the storyteller.", location, instruction, problem)
+    }
+}
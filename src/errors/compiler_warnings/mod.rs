@@ -0,0 +1,15 @@
+use super::Warning;
+
+pub fn ambiguous_pronoun_warning(pronoun: String, chosen: String, others: Vec<String>) -> Warning {
+    Warning {
+        warning_type: "Crowded Room Warning".to_owned(),
+        warning_message: format!("When the narrator says \"{}\", both {} and {} answer to it. The storyteller assumes you meant {}, the one mentioned most recently. Use a name instead to set the record straight.", pronoun, chosen, others.join(" and "), chosen)
+    }
+}
+
+pub fn ambiguous_sentence_warning(sentence: String, chosen: String, others: Vec<String>) -> Warning {
+    Warning {
+        warning_type: "Double Reading Warning".to_owned(),
+        warning_message: format!("The sentence \"{}\" could mean {} or {}. The storyteller assumes you meant {}. Move the words that do not belong to the main clause after a comma, or rephrase the sentence, to set the record straight.", sentence, chosen, others.join(" or "), chosen)
+    }
+}
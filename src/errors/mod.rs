@@ -1,6 +1,7 @@
 use colored::Colorize;
 pub mod compiler_errors;
 pub mod runtime_errors;
+pub mod compiler_warnings;
 
 const EXIT_FAILURE: i32 = 1;
 
@@ -13,8 +14,34 @@ impl Error {
     fn get_error(&self) -> String {
         format!("{}\n{}", self.error_type.red().bold(), self.error_message)
     }
-    pub fn display(&self) {
+    pub fn get_plain_error(&self) -> String {
+        format!("{}\n{}", self.error_type, self.error_message)
+    }
+    pub fn print(&self) {
         println!("{}", self.get_error());
+    }
+    pub fn display(&self) {
+        self.print();
         std::process::exit(EXIT_FAILURE);
     }
-}
\ No newline at end of file
+    pub fn display_all(errors: &[Error]) {
+        errors.iter().for_each(|error| error.print());
+        std::process::exit(EXIT_FAILURE);
+    }
+}
+pub struct Warning {
+    warning_type: String,
+    warning_message: String
+}
+
+impl Warning {
+    fn get_warning(&self) -> String {
+        format!("{}\n{}", self.warning_type.yellow().bold(), self.warning_message)
+    }
+    pub fn get_plain_warning(&self) -> String {
+        format!("{}\n{}", self.warning_type, self.warning_message)
+    }
+    pub fn display(&self) {
+        eprintln!("{}", self.get_warning());
+    }
+}
@@ -23,6 +23,13 @@ pub fn variable_not_found(variable: String) -> Error {
     }
 }
 
+pub fn number_input_error() -> Error {
+    Error {
+        error_type: "Miscounted Coins Error".to_owned(),
+        error_message: "Your character emptied their purse to count the coins, but found pebbles, buttons and lint among them. Only whole numbers written in plain digits can be counted.".to_owned()
+    }
+}
+
 pub fn label_not_found() -> Error {
     Error {
         error_type: "Place Not Found Error".to_owned(),
@@ -0,0 +1,62 @@
+use crate::lexer::lexer_types::{LexerOutput, LexerBlock, LexerToken};
+
+const CLOSING_CHARACTERS: &str = ";)]}%…";
+const OPENING_CHARACTERS: &str = "([{";
+
+fn is_number(token: &LexerToken) -> bool {
+    matches!(token, LexerToken::Text(word) if word.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn needs_space_before(tokens: &[LexerToken], index: usize, in_quote: bool) -> bool {
+    let token = &tokens[index];
+    let previous_opens = match index.checked_sub(1).map(|previous| &tokens[previous]) {
+        None => return false,
+        Some(LexerToken::Quote) => in_quote,
+        Some(LexerToken::Unknown(character)) => OPENING_CHARACTERS.contains(*character) || *character == '/',
+        Some(LexerToken::Period) => index >= 2 && is_number(&tokens[index - 2]) && is_number(token),
+        Some(_) => false
+    };
+    match token {
+        LexerToken::Comma | LexerToken::Colon | LexerToken::Period | LexerToken::QuestionMark | LexerToken::ExclamationMark => false,
+        LexerToken::Quote => !in_quote,
+        LexerToken::Unknown(character) if CLOSING_CHARACTERS.contains(*character) || *character == '/' => false,
+        LexerToken::Unknown('\'' | '’') if matches!(tokens[index - 1], LexerToken::Text(_)) => false,
+        _ => !previous_opens
+    }
+}
+
+fn format_token(token: &LexerToken) -> String {
+    match token {
+        LexerToken::Comma => ",".to_string(),
+        LexerToken::Colon => ":".to_string(),
+        LexerToken::Quote => "\"".to_string(),
+        LexerToken::Period => ".".to_string(),
+        LexerToken::QuestionMark => "?".to_string(),
+        LexerToken::ExclamationMark => "!".to_string(),
+        LexerToken::Text(word) => word.clone(),
+        LexerToken::PronounDeclaration(pronouns) => format!("({})", pronouns.join("/")),
+        LexerToken::Unknown(character) => character.to_string()
+    }
+}
+
+fn format_block(block: &LexerBlock) -> String {
+    let mut paragraph = String::new();
+    let mut in_quote = false;
+    for (index, token) in block.0.iter().enumerate() {
+        if needs_space_before(&block.0, index, in_quote) {
+            paragraph.push(' ');
+        }
+        paragraph.push_str(&format_token(token));
+        if *token == LexerToken::Quote {
+            in_quote = !in_quote;
+        }
+    }
+    paragraph
+}
+
+pub fn format_story(lexer_output: &LexerOutput) -> String {
+    lexer_output.0.iter()
+        .map(|block| format!("{}\n", format_block(block)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -3,20 +3,9 @@ use std::io::{Write, BufRead};
 use std::collections::HashMap;
 use std::ops::Rem;
 use crate::ast_to_ir::ir::{Variable, self};
-use crate::errors::runtime_errors::{input_error, variable_not_found, label_not_found, output_error};
-
-fn get_labels(ir: &Vec<ir::Instruction>) -> HashMap<BigUint, usize> {
-    let mut labels = HashMap::new();
-    ir.iter().enumerate().for_each(|(i, instruction)| {
-        match instruction {
-            ir::Instruction::Label(label) => {
-                labels.insert(label.clone(), i);
-            }
-            _ => {}
-        }
-    });
-    labels
-}
+use crate::options::Options;
+use crate::random::Random;
+use crate::errors::runtime_errors::{input_error, variable_not_found, label_not_found, output_error, number_input_error};
 
 fn get_variable_value(variable: Variable, variable_values: &mut HashMap<Variable, BigUint>) -> BigUint {
     match variable_values.get(&variable) {
@@ -38,7 +27,7 @@ fn number_to_string(value: BigUint) -> String {
     while value > 0u8.into() {
         let num = value.clone().rem(1000u32).to_u32_digits()[0] % 128u32;
         result.push((num as u8) as char);
-        value = value / 1000u32;
+        value /= 1000u32;
     }
     result.chars().rev().collect()
 }
@@ -51,7 +40,35 @@ fn string_to_number(input: &str) -> BigUint {
     result
 }
 
-fn evaluate_condition(condition: ir::Condition, variable_values: &mut HashMap<Variable, BigUint>) -> bool {
+fn decimal_string_to_number(input: &str) -> Option<BigUint> {
+    if input.is_empty() || !input.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    input.parse::<i64>().ok().map(|value| BigUint::from(value as u64))
+}
+
+fn is_end_of_input(input_stream: &mut dyn BufRead) -> bool {
+    match input_stream.fill_buf() {
+        Ok(buffer) => buffer.is_empty(),
+        Err(_) => { input_error().display(); true }
+    }
+}
+
+fn read_character(input_stream: &mut dyn BufRead) -> BigUint {
+    let character = match input_stream.fill_buf() {
+        Ok(buffer) => buffer.first().copied(),
+        Err(_) => { input_error().display(); None }
+    };
+    match character {
+        Some(character) => {
+            input_stream.consume(1);
+            BigUint::from(character)
+        }
+        None => BigUint::from(0u8)
+    }
+}
+
+fn evaluate_condition(condition: ir::Condition, variable_values: &mut HashMap<Variable, BigUint>, input_stream: &mut dyn BufRead) -> bool {
     match condition {
         ir::Condition::EqualTo(lhs, rhs) => {
             get_expression_value(lhs, variable_values) == get_expression_value(rhs, variable_values)
@@ -65,16 +82,20 @@ fn evaluate_condition(condition: ir::Condition, variable_values: &mut HashMap<Va
         ir::Condition::LessThan(lhs, rhs) => {
             get_expression_value(lhs, variable_values) < get_expression_value(rhs, variable_values)
         }
+        ir::Condition::EndOfInput => {
+            is_end_of_input(input_stream)
+        }
     }
 }
 
-pub fn interpret(ir: Vec<ir::Instruction>, input_stream: &mut dyn std::io::BufRead, output_stream: &mut dyn std::io::Write) {
+pub fn interpret(program: ir::Program, options: &Options, input_stream: &mut dyn std::io::BufRead, output_stream: &mut dyn std::io::Write) {
     let mut variable_values: HashMap<Variable, BigUint> = HashMap::new();
-    let labels = get_labels(&ir);
+    let ir = program.instructions;
+    let mut random = Random::new(options.seed);
     let mut instruction_pointer = 0;
     while instruction_pointer < ir.len() {
         let instruction = ir[instruction_pointer].clone();
-        let new_instruction_pointer = interpret_instruction(instruction, &mut variable_values, &labels, instruction_pointer, input_stream, output_stream);
+        let new_instruction_pointer = interpret_instruction(instruction, &mut variable_values, &program.labels, &mut random, instruction_pointer, input_stream, output_stream);
         match new_instruction_pointer {
             Some(new_instruction_pointer) => instruction_pointer = new_instruction_pointer,
             None => break
@@ -82,7 +103,7 @@ pub fn interpret(ir: Vec<ir::Instruction>, input_stream: &mut dyn std::io::BufRe
     }
 }
 
-fn interpret_instruction(instruction: ir::Instruction, variable_values: &mut HashMap<Variable, BigUint>, labels: &HashMap<BigUint, usize>, instruction_pointer: usize, input_stream:&mut dyn BufRead, output_stream: &mut dyn Write) -> Option<usize> {
+fn interpret_instruction(instruction: ir::Instruction, variable_values: &mut HashMap<Variable, BigUint>, labels: &HashMap<BigUint, usize>, random: &mut Random, instruction_pointer: usize, input_stream:&mut dyn BufRead, output_stream: &mut dyn Write) -> Option<usize> {
     match instruction {
         ir::Instruction::AssignmentInstruction(variable, expression) => {
             let expr_value = get_expression_value(expression, variable_values);
@@ -96,6 +117,10 @@ fn interpret_instruction(instruction: ir::Instruction, variable_values: &mut Has
             let new_value = get_variable_value(variable.clone(), variable_values) - get_expression_value(expression, variable_values);
             variable_values.insert(variable.clone(), new_value);
         }
+        ir::Instruction::RandomInstruction(variable, expression) => {
+            let sides = i64::try_from(get_expression_value(expression, variable_values)).unwrap_or(i64::MAX) as u64;
+            variable_values.insert(variable, BigUint::from(random.roll(sides)));
+        }
         ir::Instruction::PrintNumberInstruction(variable) => {
             match write!(output_stream, "{}", get_variable_value(variable.clone(), variable_values)) {
                 Ok(_) => {}
@@ -117,6 +142,22 @@ fn interpret_instruction(instruction: ir::Instruction, variable_values: &mut Has
             let num_input = string_to_number(input.trim());
             variable_values.insert(variable.clone(), num_input);
         }
+        ir::Instruction::InputCharacterInstruction(variable) => {
+            let character = read_character(input_stream);
+            variable_values.insert(variable.clone(), character);
+        }
+        ir::Instruction::InputNumberInstruction(variable) => {
+            let mut input = String::new();
+            match input_stream.read_line(&mut input) {
+                Ok(_) => {},
+                Err(_) => { input_error().display() }
+            };
+            let num_input = match decimal_string_to_number(input.trim()) {
+                Some(value) => value,
+                None => { number_input_error().display(); return None; }
+            };
+            variable_values.insert(variable.clone(), num_input);
+        }
         ir::Instruction::ExitInstruction => {
             return None;
         }
@@ -130,8 +171,8 @@ fn interpret_instruction(instruction: ir::Instruction, variable_values: &mut Has
             return Some(new_instruction_pointer);
         }
         ir::Instruction::IfInstruction(condition, statement) => {
-            if evaluate_condition(condition, variable_values) {
-                return interpret_instruction(*statement, variable_values, labels, instruction_pointer, input_stream, output_stream)
+            if evaluate_condition(condition, variable_values, input_stream) {
+                return interpret_instruction(*statement, variable_values, labels, random, instruction_pointer, input_stream, output_stream)
             }
         }
         ir::Instruction::Label(_) => {}
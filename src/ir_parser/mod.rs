@@ -0,0 +1,131 @@
+use chumsky::prelude::{*, text::Character};
+use crate::ast_to_ir::ir;
+use crate::errors::Error;
+use crate::errors::compiler_errors;
+use crate::parser::combinators::try_map_with_message;
+use num::BigUint;
+use std::collections::HashSet;
+
+enum IrLine {
+    VariableDeclaration(ir::Variable),
+    Instruction(ir::Instruction)
+}
+
+fn inline_whitespace() -> impl Parser<char, Vec<char>, Error = Simple<char>> + Clone {
+    filter(|c: &char| c.is_inline_whitespace()).repeated()
+}
+
+fn keyword(word: &'static str) -> impl Parser<char, (), Error = Simple<char>> + Clone {
+    text::keyword(word).padded_by(inline_whitespace())
+}
+
+fn symbol(symbol: &'static str) -> impl Parser<char, &'static str, Error = Simple<char>> + Clone {
+    just(symbol).padded_by(inline_whitespace())
+}
+
+fn ir_parser() -> impl Parser<char, Vec<Option<IrLine>>, Error = Simple<char>> {
+    let inline_whitespace = inline_whitespace();
+
+    let bare_word = filter(|c: &char| ir::is_name_character(*c)).repeated().at_least(1).collect::<String>();
+    let number = try_map_with_message(bare_word, |word: String| {
+        if word.chars().all(|character| character.is_ascii_digit()) {
+            word.parse::<BigUint>().map_err(|_| "invalid number".to_string())
+        } else {
+            Err(format!("\"{}\" is not a number", word))
+        }
+    }).padded_by(inline_whitespace.clone());
+    let bare_name = try_map_with_message(bare_word, |word: String| {
+        if word.chars().all(|character| character.is_ascii_digit()) || ir::KEYWORDS.contains(&word.as_str()) {
+            Err(format!("\"{}\" cannot be used as a name without quotes", word))
+        } else {
+            Ok(word)
+        }
+    });
+    let quoted_name = just('"')
+        .ignore_then(filter(|c: &char| *c != '"' && *c != '\n').repeated().collect::<String>())
+        .then_ignore(just('"'));
+    let name = quoted_name.or(bare_name).padded_by(inline_whitespace.clone());
+    let variable = name.clone().map(ir::Variable);
+    let expression = number.clone().map(ir::Expression::NumberLiteral)
+        .or(name.clone().map(ir::Expression::Variable));
+
+    let instruction = recursive(|instruction| {
+        let comparison = expression.clone()
+            .then(symbol("==").or(symbol("!=")).or(symbol(">")).or(symbol("<")))
+            .then(expression.clone())
+            .map(|((lhs, operator), rhs)| match operator {
+                "==" => ir::Condition::EqualTo(lhs, rhs),
+                "!=" => ir::Condition::NotEqualTo(lhs, rhs),
+                ">" => ir::Condition::GreaterThan(lhs, rhs),
+                _ => ir::Condition::LessThan(lhs, rhs)
+            });
+        let condition = keyword("eof").to(ir::Condition::EndOfInput).or(comparison);
+
+        keyword("if").ignore_then(condition).then(instruction)
+            .map(|(condition, instruction)| ir::Instruction::IfInstruction(condition, Box::new(instruction)))
+        .or(keyword("exit").to(ir::Instruction::ExitInstruction))
+        .or(keyword("goto").ignore_then(expression.clone()).map(ir::Instruction::GotoInstruction))
+        .or(keyword("print").ignore_then(variable.clone()).map(ir::Instruction::PrintNumberInstruction))
+        .or(keyword("printc").ignore_then(variable.clone()).map(ir::Instruction::PrintStringInstruction))
+        .or(keyword("input").ignore_then(variable.clone()).map(ir::Instruction::InputInstruction))
+        .or(keyword("inputn").ignore_then(variable.clone()).map(ir::Instruction::InputNumberInstruction))
+        .or(keyword("inputc").ignore_then(variable.clone()).map(ir::Instruction::InputCharacterInstruction))
+        .or(variable.clone().then_ignore(symbol("+=")).then(expression.clone())
+            .map(|(lhs, rhs)| ir::Instruction::AddInstruction(lhs, rhs)))
+        .or(variable.clone().then_ignore(symbol("-=")).then(expression.clone())
+            .map(|(lhs, rhs)| ir::Instruction::SubInstruction(lhs, rhs)))
+        .or(variable.clone().then_ignore(symbol("=")).then_ignore(keyword("roll")).then(expression.clone())
+            .map(|(lhs, rhs)| ir::Instruction::RandomInstruction(lhs, rhs)))
+        .or(variable.clone().then_ignore(symbol("=")).then(expression.clone())
+            .map(|(lhs, rhs)| ir::Instruction::AssignmentInstruction(lhs, rhs)))
+    });
+
+    let label = keyword("label").ignore_then(number).then_ignore(symbol(":")).map(ir::Instruction::Label);
+    let variable_declaration = keyword("var").ignore_then(variable).map(IrLine::VariableDeclaration);
+    let comment = just('#').then(filter(|c: &char| *c != '\n').repeated());
+
+    let line = inline_whitespace
+        .ignore_then(
+            variable_declaration
+            .or(label.or(instruction).map(IrLine::Instruction))
+            .or_not()
+        )
+        .then_ignore(comment.or_not());
+
+    line.separated_by(just('\n')).then_ignore(end())
+}
+
+pub fn parse_ir(source: &str) -> Result<ir::Program, Vec<Error>> {
+    let lines = ir_parser().parse(source).map_err(|errors| errors.into_iter().map(|error| {
+        let line = source[..error.span().start.min(source.len())].matches('\n').count() + 1;
+        let found = match error.found() {
+            Some('\n') | None => "the end of the line".to_string(),
+            Some(character) => format!("\"{}\"", character)
+        };
+        compiler_errors::ir_syntax_error(line, found)
+    }).collect::<Vec<_>>())?;
+    let mut variables = vec![];
+    let mut declared_variables = HashSet::new();
+    let mut instructions = vec![];
+    let mut source_map = vec![];
+    lines.into_iter().enumerate().for_each(|(i, line)| match line {
+        Some(IrLine::VariableDeclaration(variable)) if declared_variables.insert(variable.clone()) => variables.push(variable),
+        Some(IrLine::Instruction(instruction)) => {
+            instructions.push(instruction);
+            source_map.push(ir::SourceLocation::Line(i + 1));
+        }
+        _ => {}
+    });
+    let mut undeclared_variables = instructions.iter()
+        .flat_map(ir::Instruction::variables)
+        .filter(|name| !declared_variables.contains(&ir::Variable(name.to_string())))
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    undeclared_variables.sort();
+    undeclared_variables.dedup();
+    if undeclared_variables.is_empty() {
+        Ok(ir::Program::new(instructions, variables, source_map, ir::Metadata::default()))
+    } else {
+        Err(undeclared_variables.into_iter().map(compiler_errors::undeclared_variable_error).collect())
+    }
+}
@@ -1,10 +1,72 @@
 use crate::ast_to_ir::ir::{self};
+use crate::errors::{Error, runtime_errors};
 use super::*;
 
 pub fn generate_helper_functions(c_code: &mut String) {
     generate_string_to_number_function(c_code);
+    generate_decimal_string_to_number_function(c_code);
     generate_number_to_string_function(c_code);
     generate_get_input_function(c_code);
+    generate_get_character_function(c_code);
+    generate_end_of_input_function(c_code);
+    generate_random_functions(c_code);
+}
+
+fn generate_random_functions(c_code: &mut String) {
+    c_code.push_str("\
+    unsigned long long int random_state = 0;
+    unsigned long long int next_random() {
+        random_state += 0x9E3779B97F4A7C15ULL;
+        unsigned long long int z = random_state;
+        z = (z ^ (z >> 30)) * 0xBF58476D1CE4E5B9ULL;
+        z = (z ^ (z >> 27)) * 0x94D049BB133111EBULL;
+        return z ^ (z >> 31);
+    }
+    long long int roll_die(long long int sides) {
+        if (sides == 0) {
+            return 0;
+        }
+        if (sides < 0) {
+            sides = LLONG_MAX;
+        }
+        unsigned long long int n = sides;
+        unsigned long long int threshold = (0 - n) % n;
+        unsigned long long int value;
+        do {
+            value = next_random();
+        } while (value < threshold);
+        return (long long int) (value % n) + 1;
+    }\n");
+}
+
+pub fn generate_random_seed_initialization(c_code: &mut String, seed: Option<u64>) {
+    match seed {
+        Some(seed) => c_code.push_str(format!("random_state = {}ULL;\n", seed).as_str()),
+        None => c_code.push_str("random_state = (unsigned long long int) time(NULL);\n")
+    }
+}
+
+fn generate_get_character_function(c_code: &mut String) {
+    c_code.push_str("\
+    long long int get_character() {
+        int ch = getchar();
+        if (ch == EOF) {
+            return 0;
+        }
+        return ch;
+    }\n");
+}
+
+fn generate_end_of_input_function(c_code: &mut String) {
+    c_code.push_str("\
+    int end_of_input() {
+        int ch = getchar();
+        if (ch == EOF) {
+            return 1;
+        }
+        ungetc(ch, stdin);
+        return 0;
+    }\n");
 }
 
 fn generate_get_input_function(c_code: &mut String) {
@@ -55,6 +117,31 @@ fn generate_string_to_number_function(c_code: &mut String) {
     }\n");
 }
 
+fn generate_decimal_string_to_number_function(c_code: &mut String) {
+    c_code.push_str(format!("\
+    long long int decimal_string_to_number(char *string) {{
+        long long int start = 0;
+        long long int end = strlen(string);
+        long long int result = 0;
+        while (start < end && isspace((unsigned char) string[start])) start++;
+        while (end > start && isspace((unsigned char) string[end - 1])) end--;
+        if (start == end) {{
+            {}
+        }}
+        for (long long int i = start; i < end; i++) {{
+            if (!isdigit((unsigned char) string[i]) || result > (LLONG_MAX - (string[i] - '0')) / 10) {{
+                {}
+            }}
+            result = result * 10 + (string[i] - '0');
+        }}
+        return result;
+    }}\n", get_c_for_runtime_error(runtime_errors::number_input_error()), get_c_for_runtime_error(runtime_errors::number_input_error())).as_str());
+}
+
+fn get_c_for_runtime_error(error: Error) -> String {
+    format!("printf(\"%s\\n\", \"{}\"); exit(1);", error.get_plain_error().escape_default())
+}
+
 pub fn get_c_for_input_insruction(variable: ir::Variable) -> String {
     format!("\
     bufferSize = 100; \n\
@@ -68,6 +155,19 @@ pub fn get_c_for_input_insruction(variable: ir::Variable) -> String {
     free(input);\n", ir_variable_to_c_variable(&variable))
 }
 
+pub fn get_c_for_input_number_instruction(variable: ir::Variable) -> String {
+    format!("\
+    bufferSize = 100; \n\
+    input = (char *) malloc(bufferSize * sizeof(char)); \n\
+    if (input == NULL) {{ \n\
+        printf(\"Memory allocation failed\\n\"); \n\
+        return 1; \n\
+    }} \n\
+    get_input(input, bufferSize); \n\
+    {} = decimal_string_to_number(input); \n\
+    free(input);\n", ir_variable_to_c_variable(&variable))
+}
+
 pub fn get_c_for_print_string_instruction(variable: ir::Variable) -> String {
     format!("\
     output = (char *) malloc(100 * sizeof(char)); \n\
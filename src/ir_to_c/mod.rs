@@ -1,21 +1,22 @@
 mod codegen_utils;
-use std::collections::HashSet;
 use num::BigUint;
 use crate::ast_to_ir::ir::{self, Instruction};
+use crate::options::Options;
 
 
-pub fn convert_ir_to_c(ir: Vec<ir::Instruction>, variables: HashSet<ir::Variable>) -> String {
+pub fn convert_ir_to_c(program: ir::Program, options: &Options) -> String {
     let mut c_code = String::new();
     generate_imports(&mut c_code);
-    generate_macros(&mut c_code, &ir);
+    generate_macros(&mut c_code, &program);
     codegen_utils::generate_helper_functions(&mut c_code);
-    generate_main_function(&mut c_code, variables, ir);
+    generate_main_function(&mut c_code, program.variables, program.instructions, options);
     c_code
 }
 
-fn generate_main_function(c_code: &mut String, variables: HashSet<ir::Variable>, ir: Vec<Instruction>) {
+fn generate_main_function(c_code: &mut String, variables: Vec<ir::Variable>, ir: Vec<Instruction>, options: &Options) {
     generate_main_scope_entry(c_code);
     generate_variable_initializations(c_code, variables);
+    codegen_utils::generate_random_seed_initialization(c_code, options.seed);
     for instruction in ir {
         c_code.push_str(&instruction_to_c(instruction));
     }
@@ -23,18 +24,18 @@ fn generate_main_function(c_code: &mut String, variables: HashSet<ir::Variable>,
 }
 
 fn generate_scope_exit(c_code: &mut String) {
-    c_code.push_str("}");
+    c_code.push('}');
 }
 
 fn generate_main_scope_entry(c_code: &mut String) {
     c_code.push_str("int main() {\n");
 }
 
-fn generate_macros(c_code: &mut String, ir: &Vec<Instruction>) {
-    c_code.push_str(get_goto_macro(ir).as_str());
+fn generate_macros(c_code: &mut String, program: &ir::Program) {
+    c_code.push_str(get_goto_macro(program).as_str());
 }
 
-fn generate_variable_initializations(c_code: &mut String, variables: HashSet<ir::Variable>) {
+fn generate_variable_initializations(c_code: &mut String, variables: Vec<ir::Variable>) {
     c_code.push_str("char *input = NULL;\n");
     c_code.push_str("char *output = NULL;\n");
     c_code.push_str("int bufferSize = NULL;\n");
@@ -45,6 +46,9 @@ fn generate_imports(c_code: &mut String) {
     c_code.push_str("#include <stdio.h>\n");
     c_code.push_str("#include <stdlib.h>\n");
     c_code.push_str("#include <string.h>\n");
+    c_code.push_str("#include <ctype.h>\n");
+    c_code.push_str("#include <limits.h>\n");
+    c_code.push_str("#include <time.h>\n");
 }
 
 fn instruction_to_c(instruction: ir::Instruction) -> String {
@@ -58,8 +62,11 @@ fn instruction_to_c(instruction: ir::Instruction) -> String {
         Instruction::SubInstruction(lhs, rhs) => {
             format!("{} -= {};\n", ir_variable_to_c_variable(&lhs), ir_expression_to_c(rhs))
         }
+        Instruction::RandomInstruction(lhs, rhs) => {
+            format!("{} = roll_die({});\n", ir_variable_to_c_variable(&lhs), ir_expression_to_c(rhs))
+        }
         Instruction::PrintNumberInstruction(variable) => {
-            format!("printf(\"%d\", {});\n", ir_variable_to_c_variable(&variable))
+            format!("printf(\"%lld\", {});\n", ir_variable_to_c_variable(&variable))
         }
         Instruction::PrintStringInstruction(variable) => {
             codegen_utils::get_c_for_print_string_instruction(variable)
@@ -67,6 +74,12 @@ fn instruction_to_c(instruction: ir::Instruction) -> String {
         Instruction::InputInstruction(variable) => {
             codegen_utils::get_c_for_input_insruction(variable)
         }
+        Instruction::InputNumberInstruction(variable) => {
+            codegen_utils::get_c_for_input_number_instruction(variable)
+        }
+        Instruction::InputCharacterInstruction(variable) => {
+            format!("{} = get_character();\n", ir_variable_to_c_variable(&variable))
+        }
         Instruction::ExitInstruction => {
             "exit(0);\n".to_string()
         }
@@ -90,7 +103,12 @@ fn convert_to_label(label: BigUint) -> String {
 }
 
 fn ir_variable_to_c_variable(variable: &ir::Variable) -> String {
-    format!("var_{}", variable.0.replace(" ", "_"))
+    let name = variable.0.chars().map(|c| match c {
+        ' ' => "_".to_string(),
+        c if c.is_ascii_alphanumeric() => c.to_string(),
+        c => format!("__{:x}__", c as u32)
+    }).collect::<String>();
+    format!("var_{}", name)
 }
 
 fn ir_expression_to_c(expression: ir::Expression) -> String {
@@ -118,19 +136,18 @@ fn condition_to_c(condition: ir::Condition) -> String {
         ir::Condition::LessThan(lhs, rhs) => {
             format!("{} < {}", ir_expression_to_c(lhs), ir_expression_to_c(rhs))
         }
+        ir::Condition::EndOfInput => {
+            "end_of_input()".to_string()
+        }
     }
 }
 
-fn get_goto_macro(ir: &Vec<ir::Instruction>) -> String {
-    let mut labels = HashSet::new();
-    for instruction in ir {
-        match instruction {
-            Instruction::Label(label) => {
-                labels.insert(label);
-            }
-            _ => {}
-        }
-    }
+fn get_goto_macro(program: &ir::Program) -> String {
+    let labels = program.instructions.iter().enumerate()
+        .filter_map(|(i, instruction)| match instruction {
+            Instruction::Label(label) if program.labels.get(label) == Some(&i) => Some(label),
+            _ => None
+        });
     let mut result = String::new();
     result.push_str("#define GOTO_VAR(var) \\\n");
     result.push_str("do { \\\n");
@@ -1,6 +1,47 @@
-use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::collections::{HashSet, HashMap};
+use std::env;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use crate::errors::Error;
+use crate::errors::compiler_errors;
+use crate::config::{Config, KeywordCategoryConfig};
+use crate::morphology::Morphology;
+
+const KEYWORDS_PATH_VARIABLE: &str = "STORYTELLER_KEYWORDS_PATH";
+
+const TO_BE_KEYWORDS_FILE: &str = "to_be_keywords.txt";
+const POSITIVE_ADJECTIVE_KEYWORDS_FILE: &str = "positive_adjective_keywords.txt";
+const NEGATIVE_ADJECTIVE_KEYWORDS_FILE: &str = "negative_adjective_keywords.txt";
+const SAID_KEYWORDS_FILE: &str = "said_keywords.txt";
+const GOTO_KEYWORDS_FILE: &str = "goto_keywords.txt";
+const POSITIVE_COMPARATIVE_ADJECTIVE_KEYWORDS_FILE: &str = "positive_comparative_adjective_keywords.txt";
+const NEGATIVE_COMPARATIVE_ADJECTIVE_KEYWORDS_FILE: &str = "negative_comparative_adjective_keywords.txt";
+const PRONOUN_KEYWORDS_FILE: &str = "pronoun_keywords.txt";
+
+pub const DEFAULT_LANGUAGE: &str = "english";
+
+pub struct Phrases {
+    pub feel: HashSet<String>,
+    pub as_before_adjective: HashSet<String>,
+    pub as_after_adjective: HashSet<String>,
+    pub look: HashSet<String>,
+    pub up_to_the_skies: HashSet<String>,
+    pub waiting_for_an_answer: HashSet<String>,
+    pub glance: HashSet<String>,
+    pub count_the_coins: HashSet<String>,
+    pub in_hand: HashSet<String>,
+    pub roll: HashSet<String>,
+    pub a_die_with: HashSet<String>,
+    pub sides: HashSet<String>,
+    pub adverb_suffix: HashSet<String>,
+    pub end: HashSet<String>,
+    pub if_keyword: HashSet<String>,
+    pub then_keyword: HashSet<String>,
+    pub than: HashSet<String>,
+    pub not: HashSet<String>,
+    pub the_skies_fell_silent: HashSet<String>,
+    pub at_place: HashSet<String>
+}
 
 pub struct KeywordDefs {
     pub to_be: HashSet<String>,
@@ -9,30 +50,324 @@ pub struct KeywordDefs {
     pub said: HashSet<String>,
     pub goto: HashSet<String>,
     pub positive_comparative_adjective: HashSet<String>,
-    pub negative_comparative_adjective: HashSet<String>
+    pub negative_comparative_adjective: HashSet<String>,
+    pub pronouns: HashSet<String>,
+    pub phrases: Phrases
+}
+
+struct LanguagePack {
+    name: &'static str,
+    morphology: Morphology,
+    to_be: &'static str,
+    positive_adjective: &'static str,
+    negative_adjective: &'static str,
+    said: &'static str,
+    goto: &'static str,
+    positive_comparative_adjective: &'static str,
+    negative_comparative_adjective: &'static str,
+    pronouns: &'static str,
+    phrases: &'static str
+}
+
+macro_rules! language_pack {
+    ($name:literal, $morphology:expr) => {
+        LanguagePack {
+            name: $name,
+            morphology: $morphology,
+            to_be: include_str!(concat!("../../languages/", $name, "/to_be_keywords.txt")),
+            positive_adjective: include_str!(concat!("../../languages/", $name, "/positive_adjective_keywords.txt")),
+            negative_adjective: include_str!(concat!("../../languages/", $name, "/negative_adjective_keywords.txt")),
+            said: include_str!(concat!("../../languages/", $name, "/said_keywords.txt")),
+            goto: include_str!(concat!("../../languages/", $name, "/goto_keywords.txt")),
+            positive_comparative_adjective: include_str!(concat!("../../languages/", $name, "/positive_comparative_adjective_keywords.txt")),
+            negative_comparative_adjective: include_str!(concat!("../../languages/", $name, "/negative_comparative_adjective_keywords.txt")),
+            pronouns: include_str!(concat!("../../languages/", $name, "/pronoun_keywords.txt")),
+            phrases: include_str!(concat!("../../languages/", $name, "/phrases.txt"))
+        }
+    };
+}
+
+const LANGUAGE_PACKS: [LanguagePack; 2] = [
+    language_pack!("english", Morphology::English),
+    language_pack!("spanish", Morphology::None)
+];
+
+#[derive(Clone)]
+struct KeywordLocation {
+    file: String,
+    line: Option<usize>
+}
+
+impl fmt::Display for KeywordLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{}", self.file, line),
+            None => write!(f, "{}", self.file)
+        }
+    }
+}
+
+struct KeywordEntry {
+    keyword: String,
+    location: KeywordLocation
+}
+
+struct KeywordDictionary {
+    category: &'static str,
+    file_name: &'static str,
+    inflected: bool,
+    entries: Vec<KeywordEntry>
+}
+
+struct KeywordDictionaries {
+    to_be: KeywordDictionary,
+    positive_adjective: KeywordDictionary,
+    negative_adjective: KeywordDictionary,
+    said: KeywordDictionary,
+    goto: KeywordDictionary,
+    positive_comparative_adjective: KeywordDictionary,
+    negative_comparative_adjective: KeywordDictionary,
+    pronouns: KeywordDictionary
+}
+
+impl KeywordDictionaries {
+    fn all(&self) -> [&KeywordDictionary; 8] {
+        [&self.to_be, &self.positive_adjective, &self.negative_adjective, &self.said, &self.goto,
+            &self.positive_comparative_adjective, &self.negative_comparative_adjective, &self.pronouns]
+    }
+
+    fn all_mut(&mut self) -> [&mut KeywordDictionary; 8] {
+        [&mut self.to_be, &mut self.positive_adjective, &mut self.negative_adjective, &mut self.said, &mut self.goto,
+            &mut self.positive_comparative_adjective, &mut self.negative_comparative_adjective, &mut self.pronouns]
+    }
+}
+
+fn get_entries_from_str(contents: &str, file: &str) -> Vec<KeywordEntry> {
+    contents.lines().enumerate().map(|(index, line)| KeywordEntry {
+        keyword: line.to_string(),
+        location: KeywordLocation { file: file.to_string(), line: Some(index + 1) }
+    }).collect()
 }
 
-fn get_keywords_from_file(file_path: &str) -> HashSet<String> {
-    let file = File::open(file_path).unwrap();
-    let reader = BufReader::new(file);
+fn get_dictionary(category: &'static str, file_name: &'static str, inflected: bool, language_pack: &LanguagePack, contents: &str) -> KeywordDictionary {
+    let file = format!("languages/{}/{}", language_pack.name, file_name);
+    KeywordDictionary { category, file_name, inflected, entries: get_entries_from_str(contents, &file) }
+}
 
-    let mut string_set = HashSet::new();
+fn get_language_pack_dictionaries(language_pack: &LanguagePack) -> KeywordDictionaries {
+    KeywordDictionaries {
+        to_be: get_dictionary("to_be", TO_BE_KEYWORDS_FILE, true, language_pack, language_pack.to_be),
+        positive_adjective: get_dictionary("positive_adjective", POSITIVE_ADJECTIVE_KEYWORDS_FILE, false, language_pack, language_pack.positive_adjective),
+        negative_adjective: get_dictionary("negative_adjective", NEGATIVE_ADJECTIVE_KEYWORDS_FILE, false, language_pack, language_pack.negative_adjective),
+        said: get_dictionary("said", SAID_KEYWORDS_FILE, true, language_pack, language_pack.said),
+        goto: get_dictionary("goto", GOTO_KEYWORDS_FILE, true, language_pack, language_pack.goto),
+        positive_comparative_adjective: get_dictionary("positive_comparative_adjective", POSITIVE_COMPARATIVE_ADJECTIVE_KEYWORDS_FILE, false, language_pack, language_pack.positive_comparative_adjective),
+        negative_comparative_adjective: get_dictionary("negative_comparative_adjective", NEGATIVE_COMPARATIVE_ADJECTIVE_KEYWORDS_FILE, false, language_pack, language_pack.negative_comparative_adjective),
+        pronouns: get_dictionary("pronouns", PRONOUN_KEYWORDS_FILE, false, language_pack, language_pack.pronouns)
+    }
+}
 
-    for line in reader.lines() {
-        string_set.insert(line.unwrap());
+fn get_phrases_from_str(contents: &str, morphology: Morphology) -> Phrases {
+    let mut phrases: HashMap<&str, HashSet<String>> = contents.lines()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, alternatives)| (name.trim(), alternatives.split('|').map(|alternative| alternative.trim().to_string()).collect()))
+        .collect();
+    let mut phrase = |name: &str| phrases.remove(name).unwrap_or_else(|| panic!("Language pack is missing the \"{}\" phrase", name));
+    let mut verb_phrase = |name: &str| morphology.inflect(&phrase(name));
+    Phrases {
+        feel: verb_phrase("feel"),
+        look: verb_phrase("look"),
+        glance: verb_phrase("glance"),
+        count_the_coins: verb_phrase("count_the_coins"),
+        roll: verb_phrase("roll"),
+        as_before_adjective: phrase("as_before_adjective"),
+        as_after_adjective: phrase("as_after_adjective"),
+        up_to_the_skies: phrase("up_to_the_skies"),
+        waiting_for_an_answer: phrase("waiting_for_an_answer"),
+        in_hand: phrase("in_hand"),
+        a_die_with: phrase("a_die_with"),
+        sides: phrase("sides"),
+        adverb_suffix: phrase("adverb_suffix"),
+        end: phrase("end"),
+        if_keyword: phrase("if"),
+        then_keyword: phrase("then"),
+        than: phrase("than"),
+        not: phrase("not"),
+        the_skies_fell_silent: phrase("the_skies_fell_silent"),
+        at_place: phrase("at_place")
     }
+}
 
-    string_set
+fn get_language_pack(language: &str) -> Result<&'static LanguagePack, Error> {
+    match LANGUAGE_PACKS.iter().find(|language_pack| language_pack.name == language.to_lowercase()) {
+        Some(language_pack) => Ok(language_pack),
+        None => Err(compiler_errors::unknown_language_error(language.to_string()))
+    }
+}
+
+fn get_user_config_directory() -> Option<PathBuf> {
+    match env::var_os("XDG_CONFIG_HOME") {
+        Some(config_home) if !config_home.is_empty() => Some(PathBuf::from(config_home)),
+        _ => env::var_os("APPDATA").map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+    }
 }
 
-pub fn get_keyword_defs() -> KeywordDefs {
-    KeywordDefs {
-        to_be: get_keywords_from_file("keywords/to_be_keywords.txt"),
-        positive_adjective: get_keywords_from_file("keywords/positive_adjective_keywords.txt"),
-        negative_adjective: get_keywords_from_file("keywords/negative_adjective_keywords.txt"),
-        said: get_keywords_from_file("keywords/said_keywords.txt"),
-        goto: get_keywords_from_file("keywords/goto_keywords.txt"),
-        positive_comparative_adjective: get_keywords_from_file("keywords/positive_comparative_adjective_keywords.txt"),
-        negative_comparative_adjective: get_keywords_from_file("keywords/negative_comparative_adjective_keywords.txt")
+fn get_keyword_search_path(story_directory: Option<&Path>) -> Result<Vec<PathBuf>, Error> {
+    let mut search_path = vec![];
+    if let Some(story_directory) = story_directory {
+        search_path.push(story_directory.join("keywords"));
     }
-}
\ No newline at end of file
+    if let Some(user_config_directory) = get_user_config_directory() {
+        search_path.push(user_config_directory.join("storyteller").join("keywords"));
+    }
+    if let Some(keywords_path) = env::var_os(KEYWORDS_PATH_VARIABLE) {
+        for directory in env::split_paths(&keywords_path) {
+            if !directory.is_dir() {
+                return Err(compiler_errors::keyword_directory_not_found_error(directory.display().to_string()));
+            }
+            search_path.push(directory);
+        }
+    }
+    Ok(search_path)
+}
+
+fn extend_dictionary_from_search_path(dictionary: &mut KeywordDictionary, search_path: &[PathBuf]) -> Result<(), Error> {
+    for directory in search_path {
+        let file_path = directory.join(dictionary.file_name);
+        if file_path.is_file() {
+            match std::fs::read_to_string(&file_path) {
+                Ok(contents) => dictionary.entries.extend(get_entries_from_str(&contents, &file_path.display().to_string())),
+                Err(_) => return Err(compiler_errors::keyword_file_unreadable_error(file_path.display().to_string()))
+            }
+        }
+    }
+    Ok(())
+}
+
+fn get_config_entries(keywords: &[String], config: &Config) -> Vec<KeywordEntry> {
+    let file = match config.path {
+        Some(ref path) => path.display().to_string(),
+        None => "storyteller.toml".to_string()
+    };
+    keywords.iter().map(|keyword| KeywordEntry {
+        keyword: keyword.trim().to_lowercase(),
+        location: KeywordLocation { file: file.clone(), line: None }
+    }).collect()
+}
+
+fn apply_keyword_category_config(dictionary: &mut KeywordDictionary, category: &Option<KeywordCategoryConfig>, config: &Config) {
+    if let Some(category) = category {
+        if let Some(ref replacement) = category.replace {
+            dictionary.entries = get_config_entries(replacement, config);
+        }
+        dictionary.entries.extend(get_config_entries(&category.extend, config));
+    }
+}
+
+fn apply_config(dictionaries: &mut KeywordDictionaries, config: &Config) {
+    let keywords = &config.keywords;
+    apply_keyword_category_config(&mut dictionaries.to_be, &keywords.to_be, config);
+    apply_keyword_category_config(&mut dictionaries.positive_adjective, &keywords.positive_adjective, config);
+    apply_keyword_category_config(&mut dictionaries.negative_adjective, &keywords.negative_adjective, config);
+    apply_keyword_category_config(&mut dictionaries.said, &keywords.said, config);
+    apply_keyword_category_config(&mut dictionaries.goto, &keywords.goto, config);
+    apply_keyword_category_config(&mut dictionaries.positive_comparative_adjective, &keywords.positive_comparative_adjective, config);
+    apply_keyword_category_config(&mut dictionaries.negative_comparative_adjective, &keywords.negative_comparative_adjective, config);
+    if let Some(ref pronouns) = config.pronouns {
+        dictionaries.pronouns.entries = get_config_entries(pronouns, config);
+    }
+}
+
+fn get_entry_forms(dictionary: &KeywordDictionary, entry: &KeywordEntry, morphology: Morphology) -> HashSet<String> {
+    let keyword = HashSet::from([entry.keyword.clone()]);
+    if dictionary.inflected {
+        morphology.inflect(&keyword)
+    } else {
+        keyword
+    }
+}
+
+fn validate_dictionaries(dictionaries: &KeywordDictionaries, morphology: Morphology) -> Vec<Error> {
+    let mut errors = vec![];
+    let mut seen_forms: HashMap<String, (&KeywordDictionary, &KeywordEntry)> = HashMap::new();
+    let mut reported_conflicts = HashSet::new();
+    for dictionary in dictionaries.all() {
+        for entry in &dictionary.entries {
+            if entry.keyword.trim().is_empty() {
+                errors.push(compiler_errors::blank_keyword_error(entry.location.to_string()));
+                continue;
+            }
+            let mut forms = get_entry_forms(dictionary, entry, morphology).into_iter().collect::<Vec<_>>();
+            forms.sort();
+            for form in forms {
+                match seen_forms.get(&form) {
+                    Some((other_dictionary, other_entry)) if other_dictionary.category != dictionary.category => {
+                        if reported_conflicts.insert((other_entry.location.to_string(), entry.location.to_string())) {
+                            errors.push(compiler_errors::keyword_conflict_error(
+                                form.clone(),
+                                format!("{} ({})", other_dictionary.category, other_entry.location),
+                                format!("{} ({})", dictionary.category, entry.location)
+                            ));
+                        }
+                    },
+                    Some(_) => {},
+                    None => { seen_forms.insert(form, (dictionary, entry)); }
+                }
+            }
+        }
+    }
+    errors
+}
+
+fn get_keywords(dictionary: &KeywordDictionary, morphology: Morphology) -> HashSet<String> {
+    dictionary.entries.iter().flat_map(|entry| get_entry_forms(dictionary, entry, morphology)).collect()
+}
+
+fn get_keyword_defs_from_dictionaries(dictionaries: &KeywordDictionaries, language_pack: &LanguagePack) -> Result<KeywordDefs, Vec<Error>> {
+    let morphology = language_pack.morphology;
+    let errors = validate_dictionaries(dictionaries, morphology);
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    Ok(KeywordDefs {
+        to_be: get_keywords(&dictionaries.to_be, morphology),
+        positive_adjective: get_keywords(&dictionaries.positive_adjective, morphology),
+        negative_adjective: get_keywords(&dictionaries.negative_adjective, morphology),
+        said: get_keywords(&dictionaries.said, morphology),
+        goto: get_keywords(&dictionaries.goto, morphology),
+        positive_comparative_adjective: get_keywords(&dictionaries.positive_comparative_adjective, morphology),
+        negative_comparative_adjective: get_keywords(&dictionaries.negative_comparative_adjective, morphology),
+        pronouns: get_keywords(&dictionaries.pronouns, morphology),
+        phrases: get_phrases_from_str(language_pack.phrases, morphology)
+    })
+}
+
+#[cfg(test)]
+pub fn get_language_keyword_defs(language: &str) -> Result<KeywordDefs, Vec<Error>> {
+    let language_pack = get_language_pack(language).map_err(|error| vec![error])?;
+    get_keyword_defs_from_dictionaries(&get_language_pack_dictionaries(language_pack), language_pack)
+}
+
+#[cfg(test)]
+pub fn get_default_keyword_defs() -> KeywordDefs {
+    match get_language_keyword_defs(DEFAULT_LANGUAGE) {
+        Ok(keyword_defs) => keyword_defs,
+        Err(_) => panic!("The default language pack is invalid")
+    }
+}
+
+pub fn get_keyword_defs_from_search_path(search_path: &[PathBuf], config: &Config, language: &str) -> Result<KeywordDefs, Vec<Error>> {
+    let language_pack = get_language_pack(language).map_err(|error| vec![error])?;
+    let mut dictionaries = get_language_pack_dictionaries(language_pack);
+    for dictionary in dictionaries.all_mut() {
+        extend_dictionary_from_search_path(dictionary, search_path).map_err(|error| vec![error])?;
+    }
+    apply_config(&mut dictionaries, config);
+    get_keyword_defs_from_dictionaries(&dictionaries, language_pack)
+}
+
+pub fn get_keyword_defs(story_directory: Option<&Path>, config: &Config, language: &str) -> Result<KeywordDefs, Vec<Error>> {
+    let search_path = get_keyword_search_path(story_directory).map_err(|error| vec![error])?;
+    get_keyword_defs_from_search_path(&search_path, config, language)
+}
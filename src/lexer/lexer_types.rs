@@ -7,10 +7,12 @@ pub struct LexerOutput(pub Vec<LexerBlock>);
 #[derive(Debug, Clone, PartialEq, Hash, Eq)]
 pub enum LexerToken {
     Comma,
+    Colon,
     Quote,
     Period,
     QuestionMark,
     ExclamationMark,
     Text(String),
-    Unknown
+    PronounDeclaration(Vec<String>),
+    Unknown(char)
 }
\ No newline at end of file
@@ -6,26 +6,43 @@ pub fn lexer() -> impl Parser<char, LexerOutput, Error = Simple<char>> {
     let inline_whitespace = filter(|c: &char| c.is_inline_whitespace()).repeated();
     let newline = just('\n');
 
-    let digits_radix = 36;
+    let word_characters = filter(|c: &char| c.is_alphanumeric()).repeated().at_least(1).collect::<String>();
+    let word_connector = just('\'').or(just('’')).to('\'').or(just('-'));
+    let word = word_characters
+        .then(word_connector.then(word_characters).repeated())
+        .map(|(first, rest)| rest.into_iter().fold(first, |mut word, (connector, characters)| {
+            word.push(connector);
+            word.push_str(&characters);
+            word
+        }));
+
+    let pronoun_declaration = just('(')
+        .ignore_then(word.separated_by(just('/')).at_least(2))
+        .then_ignore(just(')'))
+        .map(LexerToken::PronounDeclaration);
+
     let lexer_token =
-        text::digits(digits_radix).map(|s| LexerToken::Text(s))
+        pronoun_declaration
+        .or(word.map(LexerToken::Text))
         .or(just(",").map(|_| LexerToken::Comma))
+        .or(just(":").map(|_| LexerToken::Colon))
         .or(just("\"").or(just("“").or(just("”"))).map(|_| LexerToken::Quote))
         .or(just(".").map(|_| LexerToken::Period))
         .or(just("?").map(|_| LexerToken::QuestionMark))
         .or(just("!").map(|_| LexerToken::ExclamationMark))
-        .or(filter(|c: &char| !c.is_whitespace()).map(|_| LexerToken::Unknown));
+        .or(filter(|c: &char| !c.is_whitespace()).map(LexerToken::Unknown));
 
     let block = 
         newline.not().rewind()
         .ignore_then(lexer_token.padded_by(inline_whitespace).repeated())
-        .map(|tokens| LexerBlock(tokens));
+        .map(LexerBlock);
 
-    let lexer_program = block.separated_by(newline.repeated().at_least(1))
-        .allow_trailing()
-        .allow_leading()
-        .then_ignore(end())
-        .map(|blocks| LexerOutput(blocks));
+    let paragraph_break = newline.then(inline_whitespace).repeated().at_least(1);
 
-    lexer_program
+    inline_whitespace
+        .ignore_then(block.separated_by(paragraph_break)
+            .allow_trailing()
+            .allow_leading())
+        .then_ignore(end())
+        .map(LexerOutput)
 }
\ No newline at end of file
@@ -11,36 +11,193 @@ mod ir_to_c;
 #[macro_use]
 pub mod errors;
 mod keyword_defs;
+mod morphology;
+mod config;
+mod random;
+mod formatter;
+mod emit;
+mod ir_parser;
+mod optimizer;
+pub mod options;
 use errors::compiler_errors;
-use std::collections::HashSet;
+use options::{EmitKind, Options};
+use std::path::Path;
 
-fn convert_file_contents_to_ir_and_variable_set(file_name: String) -> (Vec<ast_to_ir::ir::Instruction>, HashSet<ast_to_ir::ir::Variable>) {
-    let file_contents = match std::fs::read_to_string(file_name) {
-        Ok(file_contents) => file_contents,
-        Err(_) => { compiler_errors::file_not_found_error().display(); return (vec!(), HashSet::new()); }
-    };
+fn get_story_directory(path: &Path) -> &Path {
+    if path.is_dir() {
+        path
+    } else {
+        path.parent().unwrap_or(Path::new(""))
+    }
+}
+
+fn get_project_config(story_directory: &Path) -> config::Config {
+    match config::get_config(story_directory) {
+        Ok(config) => config,
+        Err(error) => { error.display(); config::Config::default() }
+    }
+}
+
+fn get_keyword_defs(story_directory: &Path, config: &config::Config, options: &Options) -> Result<keyword_defs::KeywordDefs, Vec<errors::Error>> {
+    let language = options.language.as_deref().unwrap_or(keyword_defs::DEFAULT_LANGUAGE);
+    keyword_defs::get_keyword_defs(Some(story_directory), config, language)
+}
+
+fn parse_file_contents(file_contents: String, keywords: &keyword_defs::KeywordDefs, options: &Options) -> Result<(parser::ast::Program, Vec<errors::Warning>), Vec<errors::Error>> {
     let lexer_output = lexer::lexer().parse(file_contents).expect("Lexer Error");
     let preprocessed_lexer_output = preprocessor::preprocess(lexer_output);
-    let keywords = keyword_defs::get_keyword_defs();
-    let ast = match parser::parse_program(preprocessed_lexer_output, keywords) {
-        Ok(ast) => ast,
-        Err(errors) => { errors.into_iter().for_each(|err| err.display()); return (vec!(), HashSet::new()); }
+    emit::emit(options, EmitKind::Tokens, || emit::tokens_to_string(&preprocessed_lexer_output)).map_err(|error| vec![error])?;
+    let (ast, warnings) = match options.jobs {
+        Some(jobs) => match rayon::ThreadPoolBuilder::new().num_threads(jobs).build() {
+            Ok(thread_pool) => thread_pool.install(|| parser::parse_program_with_warnings(preprocessed_lexer_output, keywords))?,
+            Err(_) => return Err(vec![compiler_errors::illegal_argument_error()])
+        },
+        None => parser::parse_program_with_warnings(preprocessed_lexer_output, keywords)?
     };
+    emit::emit(options, EmitKind::Ast, || emit::ast_to_string(&ast)).map_err(|error| vec![error])?;
+    emit::emit(options, EmitKind::AstJson, || emit::ast_to_json(&ast)).map_err(|error| vec![error])?;
+    Ok((ast, warnings))
+}
+
+pub fn parse_story(file_contents: String, options: &Options) -> Result<Vec<errors::Warning>, Vec<errors::Error>> {
+    let language = options.language.as_deref().unwrap_or(keyword_defs::DEFAULT_LANGUAGE);
+    let keywords = keyword_defs::get_keyword_defs(None, &config::Config::default(), language)?;
+    parse_file_contents(file_contents, &keywords, options).map(|(_, warnings)| warnings)
+}
+
+fn convert_file_contents_to_ir(file_name: String, config: &config::Config, options: &Options) -> ast_to_ir::ir::Program {
+    let program = match file_contents_to_ir(file_name.clone(), config, options) {
+        Ok((program, warnings)) => {
+            warnings.iter().for_each(|warning| warning.display());
+            program
+        }
+        Err(errors) => { errors::Error::display_all(&errors); return ast_to_ir::ir::Program::default(); }
+    };
+    let mut program = if options.optimize == Some(true) { optimizer::fold_constants(program) } else { program };
+    program.metadata.source_file = Some(file_name);
+    if cfg!(debug_assertions) || options.verify_ir == Some(true) {
+        if let Err(errors) = ast_to_ir::ir::verify::verify(&program) {
+            errors::Error::display_all(&errors);
+        }
+    }
+    let emitted = emit::emit(options, EmitKind::Ir, || emit::ir_to_string(&program))
+        .and_then(|_| emit::emit(options, EmitKind::IrJson, || emit::ir_to_json(&program)))
+        .and_then(|_| emit::emit(options, EmitKind::CfgDot, || emit::cfg_to_dot(&program)));
+    if let Err(error) = emitted {
+        error.display();
+    }
+    program
+}
+
+fn file_contents_to_ir(file_name: String, config: &config::Config, options: &Options) -> Result<(ast_to_ir::ir::Program, Vec<errors::Warning>), Vec<errors::Error>> {
+    let file_contents = std::fs::read_to_string(&file_name).map_err(|_| vec![compiler_errors::file_not_found_error()])?;
+    if Path::new(&file_name).extension().is_some_and(|extension| extension == "stir") {
+        return ir_parser::parse_ir(&file_contents).map(|program| (program, vec![]));
+    }
+    let keywords = get_keyword_defs(get_story_directory(Path::new(&file_name)), config, options)?;
+    let (ast, mut warnings) = parse_file_contents(file_contents, &keywords, options)?;
     let variables = variable_extractor::get_variables(&ast);
-    (ast_to_ir::convert_ast_to_ir(ast, &variables), variables)
+    let (program, ir_warnings) = ast_to_ir::convert_ast_to_ir_with_warnings(ast, &variables, &keywords.pronouns)?;
+    warnings.extend(ir_warnings);
+    Ok((program, warnings))
 }
 
 pub fn interpret(file_name: String, input_stream: &mut dyn std::io::BufRead, output_stream: &mut dyn Write) {
-    let (ir, _) = convert_file_contents_to_ir_and_variable_set(file_name);
-    interpreter::interpret(ir, input_stream, output_stream);
+    interpret_with_options(file_name, &Options::default(), input_stream, output_stream);
+}
+
+pub fn interpret_with_options(file_name: String, options: &Options, input_stream: &mut dyn std::io::BufRead, output_stream: &mut dyn Write) {
+    let config = get_project_config(get_story_directory(Path::new(&file_name)));
+    let options = options.with_defaults(&config.options);
+    let program = convert_file_contents_to_ir(file_name, &config, &options);
+    interpreter::interpret(program, &options, input_stream, output_stream);
+}
+
+pub fn emit_with_options(file_name: String, options: &Options) {
+    let config = get_project_config(get_story_directory(Path::new(&file_name)));
+    let options = options.with_defaults(&config.options);
+    let program = convert_file_contents_to_ir(file_name, &config, &options);
+    if let Err(error) = emit::emit(&options, EmitKind::C, || ir_to_c::convert_ir_to_c(program, &options)) {
+        error.display();
+    }
 }
 
 pub fn compile(input_file_name: String, output_file_name: String) {
-    let (ir, variables) = convert_file_contents_to_ir_and_variable_set(input_file_name);
-    let mut output_file = match std::fs::File::create(output_file_name) {
-        Ok(file) => file,
-        Err(_) => { compiler_errors::file_not_found_error().display(); return; }
-    };
-    let c_code = ir_to_c::convert_ir_to_c(ir, variables);
-    output_file.write_all(c_code.as_bytes()).expect("Error writing to output file");
-}
\ No newline at end of file
+    compile_with_options(input_file_name, output_file_name, &Options::default());
+}
+
+pub fn compile_with_options(input_file_name: String, output_file_name: String, options: &Options) {
+    let config = get_project_config(get_story_directory(Path::new(&input_file_name)));
+    let options = options.with_defaults(&config.options);
+    let program = convert_file_contents_to_ir(input_file_name, &config, &options);
+    let c_code = ir_to_c::convert_ir_to_c(program, &options);
+    let written = emit::emit(&options, EmitKind::C, || c_code.clone())
+        .and_then(|_| std::fs::write(&output_file_name, &c_code).map_err(|_| compiler_errors::output_file_error(output_file_name.clone())));
+    if let Err(error) = written {
+        error.display();
+    }
+}
+pub fn check_keywords(path: String, options: &Options) -> bool {
+    let story_directory = get_story_directory(Path::new(&path));
+    let config = get_project_config(story_directory);
+    let options = options.with_defaults(&config.options);
+    match get_keyword_defs(story_directory, &config, &options) {
+        Ok(_) => { println!("The keyword dictionaries are consistent."); true },
+        Err(errors) => { errors.iter().for_each(|err| err.print()); false }
+    }
+}
+
+fn get_story_files(paths: Vec<String>) -> Vec<String> {
+    paths.into_iter().flat_map(|path| {
+        if Path::new(&path).is_dir() {
+            let mut story_files = walkdir::WalkDir::new(&path).into_iter()
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().extension().is_some_and(|extension| extension == "story"))
+                .map(|entry| entry.path().display().to_string())
+                .collect::<Vec<_>>();
+            story_files.sort();
+            story_files
+        } else {
+            vec![path]
+        }
+    }).collect()
+}
+
+fn story_to_ir(file_contents: String, keywords: &keyword_defs::KeywordDefs) -> Result<Vec<ast_to_ir::ir::Instruction>, Vec<errors::Error>> {
+    let lexer_output = lexer::lexer().parse(file_contents).expect("Lexer Error");
+    let (ast, _) = parser::parse_program_with_warnings(preprocessor::preprocess(lexer_output), keywords)?;
+    let variables = variable_extractor::get_variables(&ast);
+    let (program, _) = ast_to_ir::convert_ast_to_ir_with_warnings(ast, &variables, &keywords.pronouns)?;
+    Ok(program.instructions)
+}
+
+fn format_file(file_name: &str, check: bool, options: &Options) -> Result<bool, Vec<errors::Error>> {
+    let story_directory = get_story_directory(Path::new(file_name));
+    let config = get_project_config(story_directory);
+    let options = options.with_defaults(&config.options);
+    let keywords = get_keyword_defs(story_directory, &config, &options)?;
+    let file_contents = std::fs::read_to_string(file_name).map_err(|_| vec![compiler_errors::file_not_found_error()])?;
+    let formatted_contents = formatter::format_story(&lexer::lexer().parse(file_contents.clone()).expect("Lexer Error"));
+    if formatted_contents == file_contents {
+        return Ok(true);
+    }
+    if story_to_ir(file_contents, &keywords)? != story_to_ir(formatted_contents.clone(), &keywords)? {
+        return Err(vec![compiler_errors::formatting_changed_meaning_error(file_name.to_string())]);
+    }
+    if check {
+        println!("{} is not formatted", file_name);
+        return Ok(false);
+    }
+    std::fs::write(file_name, formatted_contents).map_err(|_| vec![compiler_errors::file_not_found_error()])?;
+    println!("Formatted {}", file_name);
+    Ok(true)
+}
+
+pub fn format_stories(paths: Vec<String>, check: bool, options: &Options) -> bool {
+    get_story_files(paths).iter().fold(true, |all_formatted, file_name| {
+        match format_file(file_name, check, options) {
+            Ok(formatted) => formatted && all_formatted,
+            Err(errors) => { errors.iter().for_each(|err| err.print()); false }
+        }
+    })
+}
@@ -1,16 +1,92 @@
 use storyteller::errors::compiler_errors;
+use storyteller::options::{Emit, Options};
+
+fn parse_args(args: Vec<String>) -> Option<(Vec<String>, Options)> {
+    let mut file_names = vec![];
+    let mut options = Options::default();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--seed" {
+            options.seed = Some(args.next()?.parse().ok()?);
+        } else if let Some(seed) = arg.strip_prefix("--seed=") {
+            options.seed = Some(seed.parse().ok()?);
+        } else if arg == "--language" {
+            options.language = Some(args.next()?);
+        } else if let Some(language) = arg.strip_prefix("--language=") {
+            options.language = Some(language.to_string());
+        } else if arg == "--jobs" {
+            options.jobs = Some(args.next()?.parse().ok().filter(|jobs| *jobs > 0)?);
+        } else if let Some(jobs) = arg.strip_prefix("--jobs=") {
+            options.jobs = Some(jobs.parse().ok().filter(|jobs| *jobs > 0)?);
+        } else if arg == "-O" {
+            options.optimize = Some(true);
+        } else if arg == "--verify-ir" {
+            options.verify_ir = Some(true);
+        } else if arg == "--emit" {
+            options.emit = Emit::parse_list(&args.next()?)?;
+        } else if let Some(emit) = arg.strip_prefix("--emit=") {
+            options.emit = Emit::parse_list(emit)?;
+        } else if arg.starts_with('-') {
+            return None;
+        } else {
+            file_names.push(arg);
+        }
+    }
+    Some((file_names, options))
+}
+
+fn check_keywords(args: Vec<String>) {
+    let (paths, options) = match parse_args(args) {
+        Some(parsed_args) => parsed_args,
+        None => { compiler_errors::illegal_argument_error().display(); return; }
+    };
+    let path = match paths.as_slice() {
+        [] => ".".to_string(),
+        [path] => path.clone(),
+        _ => { compiler_errors::illegal_argument_error().display(); return; }
+    };
+    if !storyteller::check_keywords(path, &options) {
+        std::process::exit(1);
+    }
+}
+
+fn format_stories(args: Vec<String>) {
+    let check = args.iter().any(|arg| arg == "--check");
+    let (paths, options) = match parse_args(args.into_iter().filter(|arg| arg != "--check").collect()) {
+        Some(parsed_args) => parsed_args,
+        None => { compiler_errors::illegal_argument_error().display(); return; }
+    };
+    let paths = if paths.is_empty() { vec![".".to_string()] } else { paths };
+    if !storyteller::format_stories(paths, check, &options) {
+        std::process::exit(1);
+    }
+}
 
 fn main() {
-    let args = std::env::args().collect::<Vec<_>>();
-    let num_args = args.len();
-    if num_args == 2 {
-        let file_name = &args[1];
-        storyteller::interpret(file_name.clone(),  &mut std::io::stdin().lock(), &mut std::io::stdout());
-    } else if num_args == 3 {
-        let input_file_name = &args[1];
-        let output_file_name = &args[2];
-        storyteller::compile(input_file_name.clone(), output_file_name.clone());   
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.len() >= 2 && args[0] == "keywords" && args[1] == "check" {
+        check_keywords(args[2..].to_vec());
+        return;
+    }
+    if !args.is_empty() && args[0] == "fmt" {
+        format_stories(args[1..].to_vec());
+        return;
+    }
+    let (file_names, options) = match parse_args(args) {
+        Some(parsed_args) => parsed_args,
+        None => { compiler_errors::illegal_argument_error().display(); return; }
+    };
+    let num_file_names = file_names.len();
+    if num_file_names == 1 && !options.emit.is_empty() {
+        storyteller::emit_with_options(file_names[0].clone(), &options);
+    } else if num_file_names == 1 {
+        let file_name = &file_names[0];
+        storyteller::interpret_with_options(file_name.clone(), &options, &mut std::io::stdin().lock(), &mut std::io::stdout());
+    } else if num_file_names == 2 {
+        let input_file_name = &file_names[0];
+        let output_file_name = &file_names[1];
+        storyteller::compile_with_options(input_file_name.clone(), output_file_name.clone(), &options);
     } else {
         compiler_errors::illegal_argument_error().display();
     }
-}
\ No newline at end of file
+}
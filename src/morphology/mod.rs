@@ -0,0 +1,156 @@
+use std::collections::{HashSet, HashMap};
+use std::sync::OnceLock;
+
+const ENGLISH_IRREGULAR_VERBS: &str = include_str!("../../languages/english/irregular_verbs.txt");
+
+const ENGLISH_MODAL_AUXILIARIES: [&str; 3] = ["will", "would", "shall"];
+const ENGLISH_PERFECT_AUXILIARIES: [&str; 3] = ["has", "have", "had"];
+const ENGLISH_PROGRESSIVE_AUXILIARIES: [&str; 5] = ["is", "are", "am", "was", "were"];
+const ENGLISH_UNSTRESSED_PREFIXES: [&str; 12] = ["ad", "com", "con", "de", "ex", "in", "oc", "per", "pre", "re", "sub", "trans"];
+
+static ENGLISH_IRREGULAR_VERB_FORMS: OnceLock<HashMap<String, VerbForms>> = OnceLock::new();
+
+#[derive(Clone, Copy)]
+pub enum Morphology {
+    English,
+    None
+}
+
+struct VerbForms {
+    present: Vec<String>,
+    past: Vec<String>,
+    past_participle: Vec<String>,
+    present_participle: Vec<String>
+}
+
+fn is_vowel(character: char) -> bool {
+    "aeiou".contains(character)
+}
+
+fn ends_with_consonant_and_y(word: &str) -> bool {
+    let mut characters = word.chars().rev();
+    matches!((characters.next(), characters.next()), (Some('y'), Some(before)) if !is_vowel(before))
+}
+
+fn ends_with_consonant_vowel_consonant(word: &str) -> bool {
+    let mut characters = word.chars().rev();
+    matches!(
+        (characters.next(), characters.next(), characters.next()),
+        (Some(last), Some(vowel), Some(before)) if !is_vowel(last) && !"wxy".contains(last) && is_vowel(vowel) && !is_vowel(before)
+    )
+}
+
+fn doubles_final_consonant(verb: &str) -> bool {
+    if !ends_with_consonant_vowel_consonant(verb) {
+        return false;
+    }
+    verb.chars().filter(|character| is_vowel(*character)).count() == 1
+        || ENGLISH_UNSTRESSED_PREFIXES.iter().any(|prefix| verb.strip_prefix(prefix).is_some_and(|root| root.len() == 3))
+}
+
+fn regular_stem(verb: &str) -> String {
+    match verb.chars().last() {
+        Some(last) if doubles_final_consonant(verb) => format!("{}{}", verb, last),
+        _ => verb.to_string()
+    }
+}
+
+fn regular_third_person(verb: &str) -> String {
+    if ends_with_consonant_and_y(verb) {
+        format!("{}ies", &verb[..verb.len() - 1])
+    } else if ["s", "sh", "ch", "x", "z", "o"].iter().any(|ending| verb.ends_with(ending)) {
+        format!("{}es", verb)
+    } else {
+        format!("{}s", verb)
+    }
+}
+
+fn regular_past(verb: &str) -> String {
+    if ends_with_consonant_and_y(verb) {
+        format!("{}ied", &verb[..verb.len() - 1])
+    } else if verb.ends_with('e') {
+        format!("{}d", verb)
+    } else {
+        format!("{}ed", regular_stem(verb))
+    }
+}
+
+fn regular_present_participle(verb: &str) -> String {
+    if let Some(stem) = verb.strip_suffix("ie") {
+        format!("{}ying", stem)
+    } else if verb.ends_with('e') && !verb.ends_with("ee") && verb.len() > 2 {
+        format!("{}ing", &verb[..verb.len() - 1])
+    } else {
+        format!("{}ing", regular_stem(verb))
+    }
+}
+
+fn get_forms_from_column(column: &str) -> Vec<String> {
+    column.split(',')
+        .map(|form| form.trim().to_string())
+        .filter(|form| !form.is_empty() && form != "-")
+        .collect()
+}
+
+fn get_irregular_verbs() -> &'static HashMap<String, VerbForms> {
+    ENGLISH_IRREGULAR_VERB_FORMS.get_or_init(|| ENGLISH_IRREGULAR_VERBS.lines()
+        .filter_map(|line| line.split_once(':'))
+        .filter_map(|(verb, forms)| {
+            let columns = forms.split('|').map(get_forms_from_column).collect::<Vec<_>>();
+            match <[Vec<String>; 4]>::try_from(columns) {
+                Ok([present, past, past_participle, present_participle]) => Some((verb.trim().to_string(), VerbForms { present, past, past_participle, present_participle })),
+                Err(_) => None
+            }
+        })
+        .collect())
+}
+
+fn get_english_verb_forms(verb: &str, irregular_verbs: &HashMap<String, VerbForms>) -> VerbForms {
+    match irregular_verbs.get(verb) {
+        Some(forms) => VerbForms {
+            present: forms.present.clone(),
+            past: forms.past.clone(),
+            past_participle: forms.past_participle.clone(),
+            present_participle: forms.present_participle.clone()
+        },
+        None => VerbForms {
+            present: vec![verb.to_string(), regular_third_person(verb)],
+            past: vec![regular_past(verb)],
+            past_participle: vec![regular_past(verb)],
+            present_participle: vec![regular_present_participle(verb)]
+        }
+    }
+}
+
+fn inflect_english_lemma(lemma: &str, irregular_verbs: &HashMap<String, VerbForms>) -> HashSet<String> {
+    let (verb, rest) = match lemma.split_once(' ') {
+        Some((verb, rest)) => (verb, format!(" {}", rest)),
+        None => (lemma, "".to_string())
+    };
+    let forms = get_english_verb_forms(verb, irregular_verbs);
+    let with_auxiliaries = |auxiliaries: &[&str], verb_forms: &[String]| auxiliaries.iter()
+        .flat_map(|auxiliary| verb_forms.iter().map(move |verb_form| format!("{} {}", auxiliary, verb_form)))
+        .collect::<Vec<_>>();
+    let base = vec![verb.to_string()];
+    let mut inflections = vec![];
+    inflections.extend(forms.present.clone());
+    inflections.extend(forms.past.clone());
+    inflections.extend(forms.past_participle.clone());
+    inflections.extend(forms.present_participle.clone());
+    inflections.extend(with_auxiliaries(&ENGLISH_MODAL_AUXILIARIES, &base));
+    inflections.extend(with_auxiliaries(&ENGLISH_PERFECT_AUXILIARIES, &forms.past_participle));
+    inflections.extend(with_auxiliaries(&ENGLISH_PROGRESSIVE_AUXILIARIES, &forms.present_participle));
+    inflections.into_iter().map(|inflection| format!("{}{}", inflection, rest)).collect()
+}
+
+impl Morphology {
+    pub fn inflect(&self, lemmas: &HashSet<String>) -> HashSet<String> {
+        match self {
+            Morphology::English => {
+                let irregular_verbs = get_irregular_verbs();
+                lemmas.iter().filter(|lemma| !lemma.is_empty()).flat_map(|lemma| inflect_english_lemma(lemma, irregular_verbs)).collect()
+            },
+            Morphology::None => lemmas.clone()
+        }
+    }
+}
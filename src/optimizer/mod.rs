@@ -0,0 +1,129 @@
+use crate::ast_to_ir::ir::{Condition, Expression, Instruction, Program, Variable};
+use num::BigUint;
+use std::collections::HashMap;
+
+type KnownValues = HashMap<Variable, BigUint>;
+
+fn fits_in_c(value: &BigUint) -> bool {
+    *value <= BigUint::from(i64::MAX as u64)
+}
+
+fn substitute(expression: Expression, known_values: &KnownValues) -> Expression {
+    match expression {
+        Expression::Variable(name) => match known_values.get(&Variable(name.clone())) {
+            Some(value) => Expression::NumberLiteral(value.clone()),
+            None => Expression::Variable(name)
+        },
+        literal => literal
+    }
+}
+
+fn substitute_condition(condition: Condition, known_values: &KnownValues) -> Condition {
+    match condition {
+        Condition::EqualTo(lhs, rhs) => Condition::EqualTo(substitute(lhs, known_values), substitute(rhs, known_values)),
+        Condition::NotEqualTo(lhs, rhs) => Condition::NotEqualTo(substitute(lhs, known_values), substitute(rhs, known_values)),
+        Condition::GreaterThan(lhs, rhs) => Condition::GreaterThan(substitute(lhs, known_values), substitute(rhs, known_values)),
+        Condition::LessThan(lhs, rhs) => Condition::LessThan(substitute(lhs, known_values), substitute(rhs, known_values)),
+        Condition::EndOfInput => Condition::EndOfInput
+    }
+}
+
+fn evaluate_condition(condition: &Condition) -> Option<bool> {
+    let (lhs, rhs) = match condition {
+        Condition::EqualTo(Expression::NumberLiteral(lhs), Expression::NumberLiteral(rhs))
+        | Condition::NotEqualTo(Expression::NumberLiteral(lhs), Expression::NumberLiteral(rhs))
+        | Condition::GreaterThan(Expression::NumberLiteral(lhs), Expression::NumberLiteral(rhs))
+        | Condition::LessThan(Expression::NumberLiteral(lhs), Expression::NumberLiteral(rhs)) => (lhs, rhs),
+        _ => return None
+    };
+    match condition {
+        Condition::EqualTo(_, _) => Some(lhs == rhs),
+        Condition::NotEqualTo(_, _) => Some(lhs != rhs),
+        Condition::GreaterThan(_, _) => Some(lhs > rhs),
+        Condition::LessThan(_, _) => Some(lhs < rhs),
+        Condition::EndOfInput => None
+    }
+}
+
+fn fold_arithmetic(variable: Variable, expression: Expression, known_values: &mut KnownValues, is_addition: bool) -> Instruction {
+    let expression = substitute(expression, known_values);
+    let folded = match (known_values.get(&variable), &expression) {
+        (Some(value), Expression::NumberLiteral(operand)) if is_addition => Some(value + operand),
+        (Some(value), Expression::NumberLiteral(operand)) if value >= operand => Some(value - operand),
+        _ => None
+    };
+    match folded {
+        Some(value) if fits_in_c(&value) => {
+            known_values.insert(variable.clone(), value.clone());
+            Instruction::AssignmentInstruction(variable, Expression::NumberLiteral(value))
+        }
+        _ => {
+            known_values.remove(&variable);
+            if is_addition {
+                Instruction::AddInstruction(variable, expression)
+            } else {
+                Instruction::SubInstruction(variable, expression)
+            }
+        }
+    }
+}
+
+fn fold_instruction(instruction: Instruction, known_values: &mut KnownValues, labels: &HashMap<BigUint, usize>) -> Option<Instruction> {
+    let folded = match instruction {
+        Instruction::AssignmentInstruction(variable, expression) => {
+            let expression = substitute(expression, known_values);
+            match expression {
+                Expression::NumberLiteral(ref value) => { known_values.insert(variable.clone(), value.clone()); }
+                Expression::Variable(_) => { known_values.remove(&variable); }
+            }
+            Instruction::AssignmentInstruction(variable, expression)
+        }
+        Instruction::AddInstruction(variable, expression) => fold_arithmetic(variable, expression, known_values, true),
+        Instruction::SubInstruction(variable, expression) => fold_arithmetic(variable, expression, known_values, false),
+        Instruction::RandomInstruction(variable, expression) => {
+            let expression = substitute(expression, known_values);
+            known_values.remove(&variable);
+            Instruction::RandomInstruction(variable, expression)
+        }
+        Instruction::InputInstruction(ref variable)
+        | Instruction::InputNumberInstruction(ref variable)
+        | Instruction::InputCharacterInstruction(ref variable) => {
+            known_values.remove(variable);
+            instruction
+        }
+        Instruction::GotoInstruction(expression) => match substitute(expression.clone(), known_values) {
+            Expression::NumberLiteral(label) if labels.contains_key(&label) => Instruction::GotoInstruction(Expression::NumberLiteral(label)),
+            _ => Instruction::GotoInstruction(expression)
+        },
+        Instruction::IfInstruction(condition, inner_instruction) => {
+            let condition = substitute_condition(condition, known_values);
+            match evaluate_condition(&condition) {
+                Some(true) => return fold_instruction(*inner_instruction, known_values, labels),
+                Some(false) => return None,
+                None => {
+                    let mut inner_known_values = known_values.clone();
+                    let inner_instruction = fold_instruction(*inner_instruction, &mut inner_known_values, labels)?;
+                    known_values.retain(|variable, value| inner_known_values.get(variable) == Some(value));
+                    Instruction::IfInstruction(condition, Box::new(inner_instruction))
+                }
+            }
+        }
+        Instruction::Label(_) => {
+            known_values.clear();
+            instruction
+        }
+        Instruction::PrintNumberInstruction(_)
+        | Instruction::PrintStringInstruction(_)
+        | Instruction::ExitInstruction => instruction
+    };
+    Some(folded)
+}
+
+pub fn fold_constants(program: Program) -> Program {
+    let mut known_values = KnownValues::new();
+    let (instructions, source_map) = program.instructions.into_iter()
+        .zip(program.source_map)
+        .filter_map(|(instruction, location)| Some((fold_instruction(instruction, &mut known_values, &program.labels)?, location)))
+        .unzip();
+    Program::new(instructions, program.variables, source_map, program.metadata)
+}
@@ -0,0 +1,65 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EmitKind {
+    Tokens,
+    Ast,
+    AstJson,
+    Ir,
+    IrJson,
+    CfgDot,
+    C
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Emit {
+    pub kind: EmitKind,
+    pub path: Option<String>
+}
+
+impl Emit {
+    pub fn parse_list(list: &str) -> Option<Vec<Emit>> {
+        list.split(',').map(|item| {
+            let (kind, path) = match item.split_once('=') {
+                Some((kind, path)) => (kind, Some(path.to_string())),
+                None => (item, None)
+            };
+            let kind = match kind {
+                "tokens" => EmitKind::Tokens,
+                "ast" => EmitKind::Ast,
+                "ast-json" => EmitKind::AstJson,
+                "ir" => EmitKind::Ir,
+                "ir-json" => EmitKind::IrJson,
+                "cfg-dot" => EmitKind::CfgDot,
+                "c" => EmitKind::C,
+                _ => return None
+            };
+            Some(Emit { kind, path })
+        }).collect()
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Options {
+    pub seed: Option<u64>,
+    pub language: Option<String>,
+    pub jobs: Option<usize>,
+    pub optimize: Option<bool>,
+    pub verify_ir: Option<bool>,
+    #[serde(skip)]
+    pub emit: Vec<Emit>
+}
+
+impl Options {
+    pub fn with_defaults(&self, defaults: &Options) -> Options {
+        Options {
+            seed: self.seed.or(defaults.seed),
+            language: self.language.clone().or_else(|| defaults.language.clone()),
+            jobs: self.jobs.or(defaults.jobs),
+            optimize: self.optimize.or(defaults.optimize),
+            verify_ir: self.verify_ir.or(defaults.verify_ir),
+            emit: self.emit.clone()
+        }
+    }
+}
@@ -1,53 +1,52 @@
-#[derive(Debug, PartialEq)]
+use serde::{Serialize, Serializer};
+use serde::ser::SerializeStruct;
+
+#[derive(Debug, PartialEq, Serialize)]
 pub struct Program(pub Vec<Block>);
 
-#[derive(Debug, PartialEq, Clone)]
+// The suffix keeps statements apart from the instructions they become.
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub enum Statement {
     AssignmentStatement(Variable, VariableOrNumberLiteral),
     AddStatement(Variable, VariableOrNumberLiteral),
     SubStatement(Variable, VariableOrNumberLiteral),
+    RandomStatement(Variable, VariableOrNumberLiteral),
     PrintNumberStatement(Variable),
     PrintStringStatement(Variable),
     InputStatement(Variable),
+    InputNumberStatement(Variable),
+    InputCharacterStatement(Variable),
     ExitStatement,
     GotoStatement(VariableOrNumberLiteral),
     IfStatement(Condition, Box<Statement>),
+    PronounDeclaration(Variable, Vec<String>),
     Comment
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub enum Condition {
     EqualTo(VariableOrNumberLiteral, VariableOrNumberLiteral),
     NotEqualTo(VariableOrNumberLiteral, VariableOrNumberLiteral),
     GreaterThan(VariableOrNumberLiteral, VariableOrNumberLiteral),
     LessThan(VariableOrNumberLiteral, VariableOrNumberLiteral),
+    EndOfInput,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct Block(pub Vec<Statement>);
+pub struct Block(pub Vec<Statement>, pub Option<String>);
+
+impl Serialize for Block {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut block = serializer.serialize_struct("Block", 2)?;
+        block.serialize_field("name", &self.1)?;
+        block.serialize_field("statements", &self.0)?;
+        block.end()
+    }
+}
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub struct Variable(pub String);
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub struct VariableOrNumberLiteral(pub String);
-
-pub trait ASTExpression {
-    fn get_name(self) -> String;
-}
-
-impl ASTExpression for Variable {
-    fn get_name(self) -> String {
-        match self {
-            Variable(name) => { name }
-        }
-    }
-}
-
-impl ASTExpression for VariableOrNumberLiteral {
-    fn get_name(self) -> String {
-        match self {
-            VariableOrNumberLiteral(name) => { name }
-        }
-    }
-}
\ No newline at end of file
@@ -0,0 +1,15 @@
+use chumsky::prelude::*;
+use chumsky::BoxedParser;
+use std::hash::Hash;
+
+// chumsky's try_map closures return its Simple error by value, so the callers pass a plain message instead.
+#[allow(clippy::result_large_err)]
+pub fn try_map_with_message<'a, I, O, U, P, F>(parser: P, f: F) -> BoxedParser<'a, I, U, Simple<I>>
+where
+    I: Clone + Hash + Eq + 'a,
+    O: 'a,
+    P: Parser<I, O, Error = Simple<I>> + 'a,
+    F: Fn(O) -> Result<U, String> + 'a
+{
+    parser.try_map(move |output, span| f(output).map_err(|message| Simple::custom(span, message))).boxed()
+}
@@ -0,0 +1,69 @@
+use chumsky::prelude::*;
+use chumsky::BoxedParser;
+use std::collections::{HashSet, HashMap};
+use std::rc::Rc;
+use crate::lexer::lexer_types::LexerToken;
+use super::combinators::try_map_with_message;
+
+const ROOT: usize = 0;
+
+#[derive(Default)]
+struct KeywordTrieNode {
+    children: HashMap<String, usize>,
+    is_keyword: bool
+}
+
+pub struct KeywordTrie {
+    nodes: Vec<KeywordTrieNode>
+}
+
+impl KeywordTrie {
+    pub fn new(keywords: &HashSet<String>) -> KeywordTrie {
+        let mut trie = KeywordTrie { nodes: vec![KeywordTrieNode::default()] };
+        keywords.iter().for_each(|keyword| trie.insert(keyword));
+        trie
+    }
+
+    fn insert(&mut self, keyword: &str) {
+        let mut node = ROOT;
+        for word in keyword.split_whitespace() {
+            node = match self.nodes[node].children.get(word) {
+                Some(child) => *child,
+                None => {
+                    self.nodes.push(KeywordTrieNode::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(word.to_string(), child);
+                    child
+                }
+            };
+        }
+        if node != ROOT {
+            self.nodes[node].is_keyword = true;
+        }
+    }
+
+    pub fn parser(self) -> BoxedParser<'static, LexerToken, String, Simple<LexerToken>> {
+        node_parser(Rc::new(self), ROOT).map(|words| words.join(" ")).boxed()
+    }
+}
+
+fn node_parser(trie: Rc<KeywordTrie>, node: usize) -> BoxedParser<'static, LexerToken, Vec<String>, Simple<LexerToken>> {
+    let is_keyword = trie.nodes[node].is_keyword;
+    let children_trie = trie.clone();
+    let longer_keyword = try_map_with_message(any(), move |token: LexerToken| {
+        let child = match token {
+            LexerToken::Text(ref word) => children_trie.nodes[node].children.get(word).map(|child| (word.clone(), *child)),
+            _ => None
+        };
+        child.ok_or_else(|| format!("{:?} does not continue a keyword", token))
+    })
+    .then_with(move |(word, child)| node_parser(trie.clone(), child).map(move |mut words| {
+        words.insert(0, word.clone());
+        words
+    }));
+    if is_keyword {
+        longer_keyword.or(empty().to(vec![])).boxed()
+    } else {
+        longer_keyword.boxed()
+    }
+}
@@ -1,67 +1,172 @@
 use chumsky::prelude::*;
-use chumsky::primitive::Just;
+use chumsky::BoxedParser;
 use std::collections::HashSet;
-use itertools::Itertools;
+use rayon::prelude::*;
 use crate::lexer::lexer_types::{LexerOutput, LexerToken};
-use crate::errors::Error;
-use crate::errors::compiler_errors;
+use crate::errors::{Error, Warning};
+use crate::errors::{compiler_errors, compiler_warnings};
 pub mod ast;
+pub mod combinators;
+pub mod keyword_trie;
+use keyword_trie::KeywordTrie;
+use combinators::try_map_with_message;
 use crate::keyword_defs::KeywordDefs;
 
-fn statement_parser(keyword_defs: &KeywordDefs) -> impl Parser<LexerToken, ast::Statement, Error = Simple<LexerToken>> {
-    fn keywords(keywords: &HashSet<String>) -> impl Parser<LexerToken, LexerToken, Error = Simple<LexerToken>> {
-        fn full_keyword(full_keyword: &str) -> impl Parser<LexerToken, LexerToken, Error = Simple<LexerToken>> {
-            let full_split = full_keyword.split(" ").filter(|key| key.len() != 0).collect::<Vec<_>>();
-            let mut full_keyword_result: Box<dyn Parser<LexerToken, LexerToken, Error = Simple<LexerToken>>> = Box::new(keyword(full_split[0]));
-            for i in 1..full_split.len() {
-                full_keyword_result = Box::new(full_keyword_result.then_ignore(keyword(full_split[i])));
-            }
-            full_keyword_result
-        }
+fn keywords(keywords: &HashSet<String>) -> impl Parser<LexerToken, String, Error = Simple<LexerToken>> {
+    KeywordTrie::new(keywords).parser()
+}
 
-        let keywords = keywords.iter().map(|s| s.as_str()).unique().collect::<Vec<_>>();
-        let mut result: Box<dyn Parser<LexerToken, LexerToken, Error = Simple<LexerToken>>> = Box::new(full_keyword(keywords[0]));
-        for i in 1..keywords.len() {
-            result = Box::new(result.or(full_keyword(keywords[i])));
-        }
-        result
-    }
+fn lexer_tokens_to_name(vec: Vec<LexerToken>) -> String {
+    vec.into_iter()
+    .map(|token| match token {
+        LexerToken::Text(s) => s,
+        _ => "".to_string()
+    }).collect::<Vec<_>>().join(" ")
+}
 
-    fn keyword(keyword: &str) -> Just<LexerToken, LexerToken, Simple<LexerToken>> {
-        just(LexerToken::Text(keyword.to_string()))
-    }
+fn text_tokens_except(keyword_set: HashSet<String>, min_num_tokens: usize) -> impl Parser<LexerToken, Vec<LexerToken>, Error = Simple<LexerToken>> {
+    text_token()
+        .rewind()
+        .ignore_then(keywords(&keyword_set).not())
+        .repeated().at_least(min_num_tokens)
+}
 
-    fn lexer_tokens_to_name(vec: Vec<LexerToken>) -> String {
-        vec.into_iter()
-        .map(|token| match token {
-            LexerToken::Text(s) => s,
-            _ => "".to_string()
-        }).collect::<Vec<_>>().join(" ")
-    }
+fn text_token() -> impl Parser<LexerToken, LexerToken, Error = Simple<LexerToken>> {
+    filter(|token| matches!(token, LexerToken::Text(_)))
+}
 
-    fn text_tokens_except(token_set: HashSet<String>, min_num_tokens: usize) -> impl Parser<LexerToken, Vec<LexerToken>, Error = Simple<LexerToken>> {
-        filter(move |token| match token {
-            LexerToken::Text(text) => {
-                let t_set = token_set.iter().map(|s| s.split(" ").collect::<Vec<_>>()[0].to_string()).collect::<HashSet<_>>();
-                !t_set.contains(text)
-            },
-            _ => false
-        }).repeated().at_least(min_num_tokens)
+fn text_tokens(min_num_tokens: usize) -> impl Parser<LexerToken, Vec<LexerToken>, Error = Simple<LexerToken>>  {
+    text_token().repeated().at_least(min_num_tokens)
+}
+
+fn get_first_words(keywords: &HashSet<String>) -> HashSet<String> {
+    keywords.iter().filter_map(|keyword| keyword.split_whitespace().next()).map(|word| word.to_string()).collect()
+}
+
+fn get_verb_keywords(keyword_defs: &KeywordDefs) -> HashSet<String> {
+    let phrases = &keyword_defs.phrases;
+    [&keyword_defs.to_be, &keyword_defs.said, &keyword_defs.goto, &phrases.feel, &phrases.look, &phrases.glance, &phrases.count_the_coins, &phrases.roll]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect()
+}
+
+type StatementForm = BoxedParser<'static, LexerToken, ast::Statement, Simple<LexerToken>>;
+
+struct StatementParser {
+    if_statement: BoxedParser<'static, LexerToken, (Vec<LexerToken>, Vec<LexerToken>), Simple<LexerToken>>,
+    condition: BoxedParser<'static, LexerToken, ast::Condition, Simple<LexerToken>>,
+    forms: Vec<(HashSet<String>, StatementForm)>
+}
+
+impl StatementParser {
+    fn parse(&self, tokens: Vec<LexerToken>) -> Result<(ast::Statement, Vec<Warning>), Error> {
+        if let Ok((condition_tokens, consequence)) = self.if_statement.parse(tokens.clone()) {
+            let condition = match self.condition.parse(condition_tokens.clone()) {
+                Ok(condition) => condition,
+                Err(errors) => {
+                    let trouble_start = errors.iter().map(|error| error.span().start).max().unwrap_or(0);
+                    let trouble = lexer_tokens_to_sentence(&condition_tokens[trouble_start.min(condition_tokens.len())..]);
+                    return Err(compiler_errors::unclear_condition_error(lexer_tokens_to_sentence(&tokens), lexer_tokens_to_sentence(&condition_tokens), trouble));
+                }
+            };
+            let (consequence, warnings) = self.parse(consequence)?;
+            return Ok((ast::Statement::IfStatement(condition, Box::new(consequence)), warnings));
+        }
+        // A form can only match a sentence that contains the first word of one of its keywords.
+        let words = tokens.iter()
+            .filter_map(|token| match token {
+                LexerToken::Text(word) => Some(word.as_str()),
+                _ => None
+            })
+            .collect::<HashSet<_>>();
+        let readings = self.forms.iter()
+            .filter(|(first_words, _)| first_words.iter().any(|word| words.contains(word.as_str())))
+            .filter_map(|(_, form)| form.parse(tokens.clone()).ok().map(|statement| (form, statement)))
+            .collect::<Vec<_>>();
+        if readings.len() <= 1 {
+            let statement = readings.into_iter().next().map(|(_, statement)| statement).unwrap_or(ast::Statement::Comment);
+            return Ok((statement, vec![]));
+        }
+        let main_clause = get_main_clause(&tokens);
+        let main_clause_readings = readings.iter()
+            .filter(|(form, _)| form.parse(main_clause.clone()).is_ok())
+            .map(|(_, statement)| statement)
+            .collect::<Vec<_>>();
+        if main_clause_readings.len() == 1 {
+            return Ok((main_clause_readings[0].clone(), vec![]));
+        }
+        let chosen = main_clause_readings.first().copied().unwrap_or(&readings[0].1).clone();
+        let others = readings.iter()
+            .filter(|(_, statement)| *statement != chosen)
+            .map(|(_, statement)| describe_statement(statement))
+            .collect();
+        let warning = compiler_warnings::ambiguous_sentence_warning(lexer_tokens_to_sentence(&tokens), describe_statement(&chosen), others);
+        Ok((chosen, vec![warning]))
     }
+}
+
+fn get_main_clause(tokens: &[LexerToken]) -> Vec<LexerToken> {
+    let mut in_quote = false;
+    tokens.iter()
+        .take_while(|token| {
+            in_quote ^= **token == LexerToken::Quote;
+            in_quote || **token != LexerToken::Comma
+        })
+        .cloned()
+        .collect()
+}
+
+fn lexer_tokens_to_sentence(tokens: &[LexerToken]) -> String {
+    tokens.iter()
+        .filter_map(|token| match token {
+            LexerToken::Text(word) => Some(word.clone()),
+            LexerToken::Comma => Some(",".to_string()),
+            _ => None
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace(" ,", ",")
+}
 
-    fn text_tokens(min_num_tokens: usize) -> impl Parser<LexerToken, Vec<LexerToken>, Error = Simple<LexerToken>>  {
-        text_tokens_except(HashSet::new(), min_num_tokens)
+fn describe_statement(statement: &ast::Statement) -> String {
+    match statement {
+        ast::Statement::AssignmentStatement(lhs, rhs) => format!("setting {} to {}", lhs.0, rhs.0),
+        ast::Statement::AddStatement(lhs, rhs) => format!("adding {} to {}", rhs.0, lhs.0),
+        ast::Statement::SubStatement(lhs, rhs) => format!("subtracting {} from {}", rhs.0, lhs.0),
+        ast::Statement::RandomStatement(lhs, rhs) => format!("rolling a {}-sided die for {}", rhs.0, lhs.0),
+        ast::Statement::PrintNumberStatement(var) => format!("printing the number {} holds", var.0),
+        ast::Statement::PrintStringStatement(var) => format!("printing the character {} holds", var.0),
+        ast::Statement::InputStatement(var) => format!("reading a line into {}", var.0),
+        ast::Statement::InputNumberStatement(var) => format!("reading a number into {}", var.0),
+        ast::Statement::InputCharacterStatement(var) => format!("reading a character into {}", var.0),
+        ast::Statement::GotoStatement(place) => format!("going to {}", place.0),
+        ast::Statement::ExitStatement => "ending the story".to_string(),
+        ast::Statement::IfStatement(_, consequence) => format!("conditionally {}", describe_statement(consequence)),
+        ast::Statement::PronounDeclaration(character, _) => format!("declaring the pronouns of {}", character.0),
+        ast::Statement::Comment => "a comment".to_string()
     }
+}
 
+fn statement_parser(keyword_defs: &KeywordDefs) -> StatementParser {
     let optional_surbodinate_clause = just(LexerToken::Comma).then(any().repeated()).or_not();
-    let adverb_keyword = filter(|token: &LexerToken| match token {
-        LexerToken::Text(s) => s.ends_with("ly"),
+    let adverb_suffixes = keyword_defs.phrases.adverb_suffix.clone();
+    let adverb_keyword = filter(move |token: &LexerToken| match token {
+        LexerToken::Text(s) => adverb_suffixes.iter().any(|suffix| s.ends_with(suffix.as_str())),
         _ => false
     });
 
+    let to_be_keywords = keyword_defs.to_be.iter().map(|keyword| keyword.split_whitespace().collect::<Vec<_>>().join(" ")).collect::<HashSet<_>>();
     let assignment_statement =
         text_tokens_except(keyword_defs.to_be.clone(), 1)
-        .then_ignore(keywords(&keyword_defs.to_be))
+        .then_ignore(try_map_with_message(keywords(&get_verb_keywords(keyword_defs)), move |keyword| {
+            if to_be_keywords.contains(&keyword) {
+                Ok(())
+            } else {
+                Err(format!("\"{}\" is not a to be keyword", keyword))
+            }
+        }))
         .then(text_tokens(1))
         .then_ignore(optional_surbodinate_clause.clone())
         .then_ignore(end())
@@ -71,11 +176,11 @@ fn statement_parser(keyword_defs: &KeywordDefs) -> impl Parser<LexerToken, ast::
         ));
 
     let addition_statement =
-        text_tokens_except(HashSet::from(["felt".to_string()]), 1)
-        .then_ignore(keyword("felt"))
-        .then_ignore(keyword("as"))
+        text_tokens_except(keyword_defs.phrases.feel.clone(), 1)
+        .then_ignore(keywords(&keyword_defs.phrases.feel))
+        .then_ignore(keywords(&keyword_defs.phrases.as_before_adjective))
         .then_ignore(keywords(&keyword_defs.positive_adjective))
-        .then_ignore(keyword("as"))
+        .then_ignore(keywords(&keyword_defs.phrases.as_after_adjective))
         .then(text_tokens(1))
         .then_ignore(optional_surbodinate_clause.clone())
         .then_ignore(end())
@@ -85,11 +190,11 @@ fn statement_parser(keyword_defs: &KeywordDefs) -> impl Parser<LexerToken, ast::
         ));
 
     let subtraction_statement =
-        text_tokens_except(HashSet::from(["felt".to_string()]), 1)
-        .then_ignore(keyword("felt"))
-        .then_ignore(keyword("as"))
+        text_tokens_except(keyword_defs.phrases.feel.clone(), 1)
+        .then_ignore(keywords(&keyword_defs.phrases.feel))
+        .then_ignore(keywords(&keyword_defs.phrases.as_before_adjective))
         .then_ignore(keywords(&keyword_defs.negative_adjective))
-        .then_ignore(keyword("as"))
+        .then_ignore(keywords(&keyword_defs.phrases.as_after_adjective))
         .then(text_tokens(1))
         .then_ignore(optional_surbodinate_clause.clone())
         .then_ignore(end())
@@ -98,6 +203,19 @@ fn statement_parser(keyword_defs: &KeywordDefs) -> impl Parser<LexerToken, ast::
             ast::VariableOrNumberLiteral(lexer_tokens_to_name(b))
         ));
 
+    let random_statement =
+        text_tokens_except(keyword_defs.phrases.roll.clone(), 1)
+        .then_ignore(keywords(&keyword_defs.phrases.roll))
+        .then_ignore(keywords(&keyword_defs.phrases.a_die_with))
+        .then(text_tokens_except(keyword_defs.phrases.sides.clone(), 1))
+        .then_ignore(keywords(&keyword_defs.phrases.sides))
+        .then_ignore(optional_surbodinate_clause.clone())
+        .then_ignore(end())
+        .map(|(a, b)| ast::Statement::RandomStatement(
+            ast::Variable(lexer_tokens_to_name(a)),
+            ast::VariableOrNumberLiteral(lexer_tokens_to_name(b))
+        ));
+
     let quote = just(LexerToken::Quote);
     let comma = just(LexerToken::Comma);
     let inner_quote = none_of(vec![LexerToken::Quote]).repeated();
@@ -130,27 +248,41 @@ fn statement_parser(keyword_defs: &KeywordDefs) -> impl Parser<LexerToken, ast::
             ast::Variable(lexer_tokens_to_name(number))
         ));
 
-    let looks_keyword = HashSet::from(["looked".to_string(), "looks".to_string()]);
+    let waiting_for_an_answer = ||
+        keywords(&keyword_defs.phrases.up_to_the_skies)
+        .then(comma.clone())
+        .then(keywords(&keyword_defs.phrases.waiting_for_an_answer));
 
     let input_statement =
-        text_tokens_except(looks_keyword.clone(), 1)
-        .then_ignore(keywords(&looks_keyword))
-        .then_ignore(keyword("up"))
-        .then_ignore(keyword("to"))
-        .then_ignore(keyword("the"))
-        .then_ignore(keyword("skies"))
-        .then_ignore(keyword("beyond"))
-        .then_ignore(comma)
-        .then_ignore(keyword("waiting"))
-        .then_ignore(keyword("for"))
-        .then_ignore(keyword("an"))
-        .then_ignore(keyword("answer"))
+        text_tokens_except(keyword_defs.phrases.look.clone(), 1)
+        .then_ignore(keywords(&keyword_defs.phrases.look))
+        .then_ignore(waiting_for_an_answer())
         .then_ignore(optional_surbodinate_clause.clone())
         .then_ignore(end())
         .map(|name| ast::Statement::InputStatement(
             ast::Variable(lexer_tokens_to_name(name))
         ));
 
+    let input_character_statement =
+        text_tokens_except(keyword_defs.phrases.glance.clone(), 1)
+        .then_ignore(keywords(&keyword_defs.phrases.glance))
+        .then_ignore(waiting_for_an_answer())
+        .then_ignore(optional_surbodinate_clause.clone())
+        .then_ignore(end())
+        .map(|name| ast::Statement::InputCharacterStatement(
+            ast::Variable(lexer_tokens_to_name(name))
+        ));
+
+    let input_number_statement =
+        text_tokens_except(keyword_defs.phrases.count_the_coins.clone(), 1)
+        .then_ignore(keywords(&keyword_defs.phrases.count_the_coins))
+        .then_ignore(keywords(&keyword_defs.phrases.in_hand).then(text_tokens(1)).or_not())
+        .then_ignore(optional_surbodinate_clause.clone())
+        .then_ignore(end())
+        .map(|name| ast::Statement::InputNumberStatement(
+            ast::Variable(lexer_tokens_to_name(name))
+        ));
+
     let goto_statement =
         text_tokens_except(keyword_defs.goto.clone(), 0)
         .ignore_then(keywords(&keyword_defs.goto))
@@ -162,34 +294,37 @@ fn statement_parser(keyword_defs: &KeywordDefs) -> impl Parser<LexerToken, ast::
         ));
 
     let exit_statement =
-        take_until(keyword("end"))
+        take_until(keywords(&keyword_defs.phrases.end))
         .ignore_then(any().repeated())
         .then_ignore(end())
         .map(|_| ast::Statement::ExitStatement);
 
-    let comment =
-        take_until(end())
-        .map(|_| ast::Statement::Comment);
-
-    fn if_statement<'a>(statement_parser: Recursive<'a, LexerToken, ast::Statement, Simple<LexerToken>>, keyword_defs: &KeywordDefs) -> impl Parser<LexerToken, ast::Statement, Error = Simple<LexerToken>> + 'a {        
-        let comma = just(LexerToken::Comma);
-        let optional_surbodinate_clause = just(LexerToken::Comma).then(any::<LexerToken, Simple<LexerToken>>().repeated()).or_not();
-        let greater_than_condition = keywords(&keyword_defs.to_be).or(keyword("felt")).then(keywords(&keyword_defs.positive_comparative_adjective)).then(keyword("than"));
-        let less_than_condition = keywords(&keyword_defs.to_be).or(keyword("felt")).then(keywords(&keyword_defs.negative_comparative_adjective)).then(keyword("than"));
+    fn condition(keyword_defs: &KeywordDefs) -> impl Parser<LexerToken, ast::Condition, Error = Simple<LexerToken>> {
+        let phrases = &keyword_defs.phrases;
+        let comparison_verbs = keyword_defs.to_be.iter().chain(phrases.feel.iter()).cloned().collect::<HashSet<_>>();
+        let greater_than_condition = keywords(&comparison_verbs).then(keywords(&keyword_defs.positive_comparative_adjective)).then(keywords(&phrases.than));
+        let less_than_condition = keywords(&comparison_verbs).then(keywords(&keyword_defs.negative_comparative_adjective)).then(keywords(&phrases.than));
         let equal_to_condition = keywords(&keyword_defs.to_be); 
-        let not_equal_to_condition = keywords(&keyword_defs.to_be).ignore_then(keyword("not")); 
-        let condition_start_tokens = keyword_defs.to_be.clone().into_iter().chain(vec!["felt".to_string()]).collect::<HashSet<_>>(); 
+        let not_equal_to_condition = keywords(&keyword_defs.to_be).ignore_then(keywords(&phrases.not)); 
         
+        let end_of_input_condition =
+            keywords(&phrases.the_skies_fell_silent)
+            .then(end())
+            .map(|_| ast::Condition::EndOfInput);
+
         let condition =
-            text_tokens_except(condition_start_tokens.clone(), 1)
-            .then_ignore(greater_than_condition)
-            .then(text_tokens(1))
-            .map(|(lhs, rhs)| ast::Condition::GreaterThan(
-                ast::VariableOrNumberLiteral(lexer_tokens_to_name(lhs)),
-                ast::VariableOrNumberLiteral(lexer_tokens_to_name(rhs))
-            ))
+            end_of_input_condition
             .or(
-                text_tokens_except(condition_start_tokens.clone(), 1)
+                text_tokens_except(comparison_verbs.clone(), 1)
+                .then_ignore(greater_than_condition)
+                .then(text_tokens(1))
+                .map(|(lhs, rhs)| ast::Condition::GreaterThan(
+                    ast::VariableOrNumberLiteral(lexer_tokens_to_name(lhs)),
+                    ast::VariableOrNumberLiteral(lexer_tokens_to_name(rhs))
+                ))
+            )
+            .or(
+                text_tokens_except(comparison_verbs.clone(), 1)
                 .then_ignore(less_than_condition)
                 .then(text_tokens(1))
                 .map(|(lhs, rhs)| ast::Condition::LessThan(
@@ -198,7 +333,7 @@ fn statement_parser(keyword_defs: &KeywordDefs) -> impl Parser<LexerToken, ast::
                 ))
             )
             .or(
-                text_tokens_except(condition_start_tokens.clone(), 1)
+                text_tokens_except(comparison_verbs.clone(), 1)
                 .then_ignore(not_equal_to_condition)
                 .then(text_tokens(1))
                 .map(|(lhs, rhs)| ast::Condition::NotEqualTo(
@@ -207,7 +342,7 @@ fn statement_parser(keyword_defs: &KeywordDefs) -> impl Parser<LexerToken, ast::
                 ))
             )
             .or(
-                text_tokens_except(condition_start_tokens, 1)
+                text_tokens_except(comparison_verbs, 1)
                 .then_ignore(equal_to_condition)
                 .then(text_tokens(1))
                 .map(|(lhs, rhs)| ast::Condition::EqualTo(
@@ -215,74 +350,139 @@ fn statement_parser(keyword_defs: &KeywordDefs) -> impl Parser<LexerToken, ast::
                     ast::VariableOrNumberLiteral(lexer_tokens_to_name(rhs))
                 ))
             );
-            
+        condition.then_ignore(end())
+    }
 
-        keyword("if")
+    fn if_statement(keyword_defs: &KeywordDefs) -> impl Parser<LexerToken, (Vec<LexerToken>, Vec<LexerToken>), Error = Simple<LexerToken>> {
+        let comma = just(LexerToken::Comma);
+        let optional_surbodinate_clause = just(LexerToken::Comma).then(any::<LexerToken, Simple<LexerToken>>().repeated()).or_not();
+        let phrases = &keyword_defs.phrases;
+        keywords(&phrases.if_keyword)
         .ignore_then(text_tokens(1))
         .then_ignore(comma)
-        .then_ignore(keyword("then"))
+        .then_ignore(keywords(&phrases.then_keyword))
         .then_ignore(optional_surbodinate_clause)
         .then(take_until(end()))
-        .map(move |(condition_tokens, (consequence, _))| {
-            ast::Statement::IfStatement(
-            condition.parse(condition_tokens).unwrap(),
-            Box::new(statement_parser.parse(consequence).unwrap())
-        )})
+        .map(|(condition_tokens, (consequence, _))| (condition_tokens, consequence))
     }
 
-    let statement = recursive(|statement| {
-        if_statement(statement, keyword_defs)
-        .or(input_statement)
-        .or(print_string_statement)
-        .or(print_number_statement)
-        .or(assignment_statement)
-        .or(addition_statement)
-        .or(subtraction_statement)
-        .or(goto_statement)
-        .or(exit_statement)
-        .or(comment)
-    });
+    StatementParser {
+        if_statement: if_statement(keyword_defs).boxed(),
+        condition: condition(keyword_defs).boxed(),
+        forms: vec![
+            (get_first_words(&keyword_defs.phrases.look), input_statement.boxed()),
+            (get_first_words(&keyword_defs.phrases.count_the_coins), input_number_statement.boxed()),
+            (get_first_words(&keyword_defs.phrases.glance), input_character_statement.boxed()),
+            (get_first_words(&keyword_defs.said), print_string_statement.boxed()),
+            (get_first_words(&keyword_defs.said), print_number_statement.boxed()),
+            (get_first_words(&keyword_defs.to_be), assignment_statement.boxed()),
+            (get_first_words(&keyword_defs.phrases.feel), addition_statement.boxed()),
+            (get_first_words(&keyword_defs.phrases.feel), subtraction_statement.boxed()),
+            (get_first_words(&keyword_defs.phrases.roll), random_statement.boxed()),
+            (get_first_words(&keyword_defs.goto), goto_statement.boxed()),
+            (get_first_words(&keyword_defs.phrases.end), exit_statement.boxed())
+        ]
+    }
+}
 
-    statement
+fn extract_pronoun_declarations(tokens: Vec<LexerToken>) -> (Vec<LexerToken>, Vec<ast::Statement>) {
+    let mut remaining_tokens = vec![];
+    let mut declarations = vec![];
+    let mut preceding_words: Vec<String> = vec![];
+    let mut in_quote = false;
+    for token in tokens {
+        match token {
+            LexerToken::PronounDeclaration(pronouns) if !in_quote => {
+                if !preceding_words.is_empty() {
+                    declarations.push(ast::Statement::PronounDeclaration(ast::Variable(preceding_words.join(" ")), pronouns));
+                }
+                preceding_words.clear();
+            },
+            LexerToken::Text(ref word) => {
+                preceding_words.push(word.clone());
+                remaining_tokens.push(token);
+            },
+            _ => {
+                in_quote ^= token == LexerToken::Quote;
+                preceding_words.clear();
+                remaining_tokens.push(token);
+            }
+        }
+    }
+    (remaining_tokens, declarations)
 }
 
-fn statement_block_parser(keyword_defs: &KeywordDefs) -> impl Parser<LexerToken, ast::Block, Error = Simple<LexerToken>> + '_ {
+fn statement_block_parser(keyword_defs: &KeywordDefs) -> impl Parser<LexerToken, (Option<String>, Vec<Vec<LexerToken>>), Error = Simple<LexerToken>> {
     let sentence_end_punctuation = just(LexerToken::Period)
         .or(just(LexerToken::QuestionMark))
         .or(just(LexerToken::ExclamationMark));
 
-    let block_parser =
-        (sentence_end_punctuation.clone().not().repeated()
-            .then_ignore(sentence_end_punctuation)
-        ).repeated().at_least(1)
-        .or(
-            end().map(|_| vec!())
+    let paragraph_name_opener =
+        keywords(&keyword_defs.phrases.at_place)
+        .ignore_then(text_tokens(1))
+        .then_ignore(just(LexerToken::Colon))
+        .map(lexer_tokens_to_name);
+
+    paragraph_name_opener.or_not()
+        .then(
+            (sentence_end_punctuation.clone().not().repeated()
+                .then_ignore(sentence_end_punctuation)
+            ).repeated().at_least(1)
+            .or(
+                end().map(|_| vec!())
+            )
         )
-        .map(|statements| {
-            ast::Block(statements.into_iter()
-            .filter(|statement| !statement.is_empty())
-            .map(|statement| {
-                statement_parser(keyword_defs).parse(statement).unwrap()
-            }).collect())
-        });
-    block_parser
 }
 
-pub fn parse_program(input: LexerOutput, keywords: KeywordDefs) -> Result<ast::Program, Vec<Error>> {
-    let mut errors = vec![];
-    let program = ast::Program(input.0.into_iter().map(|block| {
-        let parsed_block = match statement_block_parser(&keywords).parse(block.0.clone()) {
-            Ok(s) => s,
-            Err(_) =>  {
-                errors.push(compiler_errors::unfinished_thought_error());
-                ast::Block(vec!())
+fn parse_sentences(sentences: Vec<Vec<LexerToken>>, statement_parser: &StatementParser, warnings: &mut Vec<Warning>, errors: &mut Vec<Error>) -> Vec<ast::Statement> {
+    sentences.into_iter()
+        .filter(|sentence| !sentence.is_empty())
+        .flat_map(|sentence| {
+            let (sentence, mut statements) = extract_pronoun_declarations(sentence);
+            match statement_parser.parse(sentence) {
+                Ok((statement, sentence_warnings)) => {
+                    statements.push(statement);
+                    warnings.extend(sentence_warnings);
+                },
+                Err(error) => errors.push(error)
             }
-        };
-        parsed_block
+            statements
+        }).collect()
+}
+
+pub fn parse_program_with_warnings(input: LexerOutput, keywords: &KeywordDefs) -> Result<(ast::Program, Vec<Warning>), Vec<Error>> {
+    let chunk_size = input.0.len().div_ceil(rayon::current_num_threads()).max(1);
+    let parsed_blocks = input.0.into_par_iter()
+        .chunks(chunk_size)
+        .map(|blocks| {
+            let block_parser = statement_block_parser(keywords);
+            let statement_parser = statement_parser(keywords);
+            blocks.into_iter().map(|block| {
+                let mut errors = vec![];
+                let mut warnings = vec![];
+                let parsed_block = match block_parser.parse(block.0) {
+                    Ok((name, sentences)) => ast::Block(parse_sentences(sentences, &statement_parser, &mut warnings, &mut errors), name),
+                    Err(_) => {
+                        errors.push(compiler_errors::unfinished_thought_error());
+                        ast::Block(vec!(), None)
+                    }
+                };
+                (parsed_block, warnings, errors)
+            }).collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .flatten();
+    let mut errors = vec![];
+    let mut warnings = vec![];
+    let program = ast::Program(parsed_blocks.map(|(block, block_warnings, block_errors)| {
+        warnings.extend(block_warnings);
+        errors.extend(block_errors);
+        block
     }).collect());
     if errors.is_empty() {
-        Ok(program)
+        Ok((program, warnings))
     } else {
         Err(errors)
     }
-}
\ No newline at end of file
+}
@@ -5,6 +5,7 @@ fn lowercase_all_tokens(tokens: LexerOutput) -> LexerOutput {
         LexerBlock(block.0.into_iter().map(|token| {
             match token {
                 LexerToken::Text(s) => LexerToken::Text(s.to_lowercase()),
+                LexerToken::PronounDeclaration(pronouns) => LexerToken::PronounDeclaration(pronouns.into_iter().map(|pronoun| pronoun.to_lowercase()).collect()),
                 _ => token
             }
         }).collect::<Vec<_>>())
@@ -21,7 +22,7 @@ fn remove_end_sentence_and_unknown_punctuation_in_quotes(tokens: LexerOutput) ->
                     in_quote = !in_quote;
                     updated_block.push(token);
                 },
-                LexerToken::Period | LexerToken::QuestionMark | LexerToken::ExclamationMark | LexerToken::Unknown => {
+                LexerToken::Period | LexerToken::QuestionMark | LexerToken::ExclamationMark | LexerToken::Colon | LexerToken::Unknown(_) => {
                     if !in_quote {
                         updated_block.push(token);
                     }
@@ -0,0 +1,35 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub struct Random {
+    state: u64
+}
+
+impl Random {
+    pub fn new(seed: Option<u64>) -> Random {
+        let seed = seed.unwrap_or_else(|| {
+            SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_nanos() as u64).unwrap_or(0)
+        });
+        Random { state: seed }
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    pub fn roll(&mut self, sides: u64) -> u64 {
+        if sides == 0 {
+            return 0;
+        }
+        let threshold = sides.wrapping_neg() % sides;
+        loop {
+            let value = self.next();
+            if value >= threshold {
+                return value % sides + 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::ast_to_ir::ir;
+use crate::errors::compiler_errors;
+use super::parser_tests::parser_test_utils::convert_program_string;
+
+#[test]
+fn goto_to_an_existing_paragraph_number_is_accepted() {
+    assert!(convert_program_string("Charlie was tired. Charlie wanted to go to 12.\n\nCharlie was incredible.\n\nCharlie smiled.").is_ok());
+}
+
+#[test]
+fn program_maps_instructions_to_sentences_and_declares_characters_in_order() {
+    let program = convert_program_string("At the Castle: Bob was 3. The sun rose. Alice was 5.\n\nAlice felt as happy as Bob.").expect("Convert to IR failed");
+
+    assert_eq!(program.variables, vec![ir::Variable("bob".to_string()), ir::Variable("alice".to_string())]);
+    assert_eq!(program.source_map, vec![
+        ir::SourceLocation::Paragraph(0), ir::SourceLocation::Sentence(0, 0), ir::SourceLocation::Sentence(0, 2),
+        ir::SourceLocation::Paragraph(1), ir::SourceLocation::Sentence(1, 0)
+    ]);
+    assert_eq!(program.labels[&1u32.into()], 3);
+    assert_eq!(program.metadata.paragraph_names[&0u32.into()], "the castle");
+}
+
+#[test]
+fn pronoun_declarations_do_not_shift_sentence_numbers() {
+    let program = convert_program_string("Alice (she/her) was 3. She was 4.").expect("Convert to IR failed");
+
+    assert_eq!(program.source_map, vec![
+        ir::SourceLocation::Paragraph(0), ir::SourceLocation::Sentence(0, 0), ir::SourceLocation::Sentence(0, 1)
+    ]);
+    assert_eq!(program.source_map[2].to_string(), "sentence 2 of paragraph 0");
+}
+
+#[test]
+fn goto_past_the_last_paragraph_is_reported_with_its_location() {
+    let errors = match convert_program_string("Charlie was tired.\n\nCharlie wanted to go to a beautiful palace.") {
+        Ok(_) => panic!("Expected a missing paragraph error"),
+        Err(errors) => errors
+    };
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].starts_with("Place Not Found Error\nIn paragraph 1, your characters set off for \"a beautiful palace\", which is paragraph 196, but the story ends with paragraph 1."));
+}
+
+#[test]
+fn missing_paragraph_error_describes_a_story_without_paragraphs() {
+    let error = compiler_errors::missing_paragraph_error("3".to_string(), "3".to_string(), 0, "paragraph 0".to_string()).get_plain_error();
+
+    assert!(error.starts_with("Place Not Found Error\nIn paragraph 0, your characters set off for paragraph 3, but the story has no paragraphs at all."));
+}
+
+#[test]
+fn numeric_goto_targets_still_work_next_to_named_paragraphs() {
+    let ir = match convert_program_string("At the castle: Alice was 3. Alice went to 1.\n\nAlice was 4.") {
+        Ok(program) => program.instructions,
+        Err(errors) => panic!("Convert to IR failed: {:?}", errors)
+    };
+
+    assert!(ir.contains(&ir::Instruction::GotoInstruction(ir::Expression::NumberLiteral(1u32.into()))));
+}
+
+#[test]
+fn goto_to_an_unnamed_place_names_the_paragraph_it_comes_from() {
+    let errors = match convert_program_string("At the Castle: Charlie wanted to go to the Tower.") {
+        Ok(_) => panic!("Expected a place not found error"),
+        Err(errors) => errors
+    };
+
+    assert!(errors[0].starts_with("Place Not Found Error\nIn the paragraph at \"the castle\", your characters pack their bags for \"the tower\""));
+}
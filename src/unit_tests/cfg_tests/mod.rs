@@ -0,0 +1,56 @@
+use crate::ir_parser;
+use crate::emit;
+use crate::ast_to_ir::ir::{self, cfg::{ControlFlowGraph, Edge, EdgeKind, Target}};
+
+fn parse(source: &str) -> ir::Program {
+    match ir_parser::parse_ir(source) {
+        Ok(program) => program,
+        Err(_) => panic!("Parse IR failed")
+    }
+}
+
+fn edge(target: Target, kind: EdgeKind) -> Edge {
+    Edge { target, kind }
+}
+
+#[test]
+fn cfg_splits_blocks_at_labels_and_jumps() {
+    let ir = parse("var alice\nlabel 0:\n    inputn alice\n    if alice > 3 goto 1\n    print alice\n    exit\nlabel 1:\n    alice -= 1\n    goto 0\n");
+    let graph = ControlFlowGraph::new(&ir);
+
+    assert_eq!(graph.blocks.iter().map(|block| (block.start, block.end)).collect::<Vec<_>>(), vec![(0, 3), (3, 5), (5, 8)]);
+    assert_eq!(graph.blocks[0].edges, vec![edge(Target::Block(2), EdgeKind::ConditionalGoto), edge(Target::Block(1), EdgeKind::FallThrough)]);
+    assert_eq!(graph.blocks[1].edges, vec![edge(Target::Exit, EdgeKind::Goto)]);
+    assert_eq!(graph.blocks[2].edges, vec![edge(Target::Block(0), EdgeKind::Goto)]);
+}
+
+#[test]
+fn cfg_sends_variable_gotos_to_every_label_and_the_end_and_marks_missing_labels() {
+    let ir = parse("var alice\nlabel 0:\n    alice = roll 2\nlabel 1:\n    goto alice\nlabel 2:\n    goto 5\n");
+    let graph = ControlFlowGraph::new(&ir);
+
+    assert_eq!(graph.blocks[0].edges, vec![edge(Target::Block(1), EdgeKind::FallThrough)]);
+    assert_eq!(graph.blocks[1].edges, vec![
+        edge(Target::Block(0), EdgeKind::VariableGoto),
+        edge(Target::Block(1), EdgeKind::VariableGoto),
+        edge(Target::Block(2), EdgeKind::VariableGoto),
+        edge(Target::Exit, EdgeKind::VariableGoto)
+    ]);
+    assert_eq!(graph.blocks[2].edges, vec![edge(Target::Missing(5u32.into()), EdgeKind::Goto)]);
+    assert!(emit::cfg_to_dot(&ir).contains("    missing5 [shape=octagon, color=red, label=\"no paragraph 5\"];\n"));
+}
+
+#[test]
+fn cfg_of_empty_story_goes_straight_to_the_end() {
+    assert_eq!(ControlFlowGraph::new(&ir::Program::default()).entry(), Target::Exit);
+}
+
+#[test]
+fn cfg_dot_escapes_names_and_styles_edges() {
+    let ir = parse("var \"the old king\"\nlabel 0:\n    \"the old king\" = 1\n    if \"the old king\" > 0 goto 0\n");
+    let dot = emit::cfg_to_dot(&ir);
+
+    assert!(dot.starts_with("digraph story {\n"));
+    assert!(dot.contains("    block0 [label=\"label 0:\\l\\\"the old king\\\" = 1\\lif \\\"the old king\\\" > 0 goto 0\\l\"];\n"));
+    assert!(dot.contains("    block0 -> block0 [style=dashed];\n    block0 -> exit;\n"));
+}
@@ -0,0 +1,30 @@
+use crate::config;
+use std::path::Path;
+
+#[test]
+fn config_reads_keywords_pronouns_and_options() {
+    let contents = "pronouns = [\"it\"]\n\n[keywords.said]\nextend = [\"murmured\"]\n\n[options]\nseed = 3\n";
+    let config = config::parse_config(contents, Path::new("storyteller.toml")).ok().unwrap();
+
+    assert_eq!(config.pronouns, Some(vec!["it".to_string()]));
+    assert_eq!(config.keywords.said.unwrap().extend, vec!["murmured".to_string()]);
+    assert_eq!(config.options.seed, Some(3));
+}
+
+#[test]
+fn config_rejects_unknown_keys() {
+    let contents = "[keywords.whispered]\nextend = [\"hissed\"]\n";
+    assert!(config::parse_config(contents, Path::new("storyteller.toml")).is_err());
+}
+
+#[test]
+fn config_rejects_empty_keyword_categories() {
+    let contents = "[keywords.to_be]\nreplace = []\n";
+    assert!(config::parse_config(contents, Path::new("storyteller.toml")).is_err());
+}
+
+#[test]
+fn config_rejects_blank_keywords() {
+    let contents = "[keywords.goto]\nextend = [\" \"]\n";
+    assert!(config::parse_config(contents, Path::new("storyteller.toml")).is_err());
+}
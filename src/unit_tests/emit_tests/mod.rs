@@ -0,0 +1,68 @@
+use crate::emit;
+use crate::options::{Emit, EmitKind, Options};
+use crate::parser::ast;
+use crate::ast_to_ir::ir;
+use num::BigUint;
+
+#[test]
+fn emit_list_parses_kinds_and_paths() {
+    assert_eq!(Emit::parse_list("tokens,ir-json=out.json"), Some(vec![
+        Emit { kind: EmitKind::Tokens, path: None },
+        Emit { kind: EmitKind::IrJson, path: Some("out.json".to_string()) }
+    ]));
+    assert_eq!(Emit::parse_list("ast,bytecode"), None);
+}
+
+#[test]
+fn ast_json_names_paragraphs_and_statements() {
+    let ast = ast::Program(vec![ast::Block(
+        vec![ast::Statement::AssignmentStatement(ast::Variable("alice".to_string()), ast::VariableOrNumberLiteral("3".to_string()))],
+        Some("the castle".to_string())
+    )]);
+    let json: serde_json::Value = serde_json::from_str(&emit::ast_to_json(&ast)).unwrap();
+
+    assert_eq!(json, serde_json::json!([
+        { "name": "the castle", "statements": [{ "AssignmentStatement": ["alice", "3"] }] }
+    ]));
+}
+
+#[test]
+fn ir_json_writes_numbers_as_strings() {
+    let big_number = "123456789012345678901234567890".parse::<BigUint>().unwrap();
+    let ir = vec![
+        ir::Instruction::Label(BigUint::from(0u32)),
+        ir::Instruction::AssignmentInstruction(ir::Variable("alice".to_string()), ir::Expression::NumberLiteral(big_number))
+    ];
+    let program = ir::Program::new(ir, vec![ir::Variable("alice".to_string())], vec![], ir::Metadata::default());
+    let json: serde_json::Value = serde_json::from_str(&emit::ir_to_json(&program)).unwrap();
+
+    assert_eq!(json, serde_json::json!([
+        { "Label": "0" },
+        { "AssignmentInstruction": ["alice", { "NumberLiteral": "123456789012345678901234567890" }] }
+    ]));
+}
+
+#[test]
+fn ir_text_indents_instructions_under_labels() {
+    let ir = vec![
+        ir::Instruction::Label(BigUint::from(0u32)),
+        ir::Instruction::PrintNumberInstruction(ir::Variable("alice".to_string())),
+        ir::Instruction::ExitInstruction
+    ];
+
+    let variables = vec![ir::Variable("the old king".to_string()), ir::Variable("alice".to_string())];
+    let program = ir::Program::new(ir, variables, vec![], ir::Metadata::default());
+
+    assert_eq!(emit::ir_to_string(&program), "var \"the old king\"\nvar alice\nlabel 0:\n    print alice\n    exit\n");
+}
+
+#[test]
+fn emit_to_an_unwritable_path_returns_an_output_file_error() {
+    let options = Options { emit: vec![Emit { kind: EmitKind::Ir, path: Some("no_such_directory/story.ir".to_string()) }], ..Options::default() };
+    let error = match emit::emit(&options, EmitKind::Ir, || "label 0:\n".to_string()) {
+        Ok(_) => panic!("Expected an output file error"),
+        Err(error) => error
+    };
+
+    assert!(error.get_plain_error().starts_with("Unwritten Chapter Error\nThe storyteller sat down to write \"no_such_directory/story.ir\""));
+}
@@ -0,0 +1,38 @@
+use crate::formatter;
+use crate::lexer;
+use chumsky::Parser;
+
+fn format_string(story: &str) -> String {
+    formatter::format_story(&lexer::lexer().parse(story).unwrap())
+}
+
+#[test]
+fn formatter_normalises_spacing_around_punctuation() {
+    assert_eq!(format_string("Alice   was 3 .Bob was 4 ,  and  Alice was 5 !"), "Alice was 3. Bob was 4, and Alice was 5!\n");
+}
+
+#[test]
+fn formatter_straightens_curly_quotes() {
+    assert_eq!(format_string("“Hello,”Alice said . “ Goodbye ” Bob said."), "\"Hello,\" Alice said. \"Goodbye\" Bob said.\n");
+}
+
+#[test]
+fn formatter_separates_paragraphs_by_one_blank_line() {
+    assert_eq!(format_string("\n  Alice was 3.\n   \n\n\nAt the castle:Bob was 4.  "), "Alice was 3.\n\nAt the castle: Bob was 4.\n");
+}
+
+#[test]
+fn formatter_keeps_pronoun_declarations_and_unknown_characters() {
+    assert_eq!(format_string("Alice ( she/her ) (She/Her) was 3 ;ok -- fine"), "Alice (she/her) (She/Her) was 3; ok - - fine\n");
+}
+
+#[test]
+fn formatter_keeps_word_final_apostrophes_and_decimals_together() {
+    assert_eq!(format_string("Jones' cat was 3.5 , and the Joneses’ dog was 0 . 25."), "Jones' cat was 3.5, and the Joneses’ dog was 0.25.\n");
+}
+
+#[test]
+fn formatter_output_is_already_formatted() {
+    let formatted = format_string("“Hi,”  Alice said.\n\n\nAlice was 3 .");
+    assert_eq!(format_string(&formatted), formatted);
+}
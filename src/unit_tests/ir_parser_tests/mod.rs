@@ -0,0 +1,83 @@
+use crate::ir_parser;
+use crate::ast_to_ir::ir;
+use crate::emit;
+use num::BigUint;
+
+fn variable(name: &str) -> ir::Variable {
+    ir::Variable(name.to_string())
+}
+
+fn number(value: u32) -> ir::Expression {
+    ir::Expression::NumberLiteral(BigUint::from(value))
+}
+
+#[test]
+fn ir_parser_reads_declarations_labels_and_instructions() {
+    let source = "# a comment\nvar alice\nvar \"the old king\"\n\nlabel 0:\n    alice = roll 6 # inline comment\n    if alice == \"the old king\" goto 0\n    if eof exit\n    \"the old king\" += alice\n";
+    let program = match ir_parser::parse_ir(source) {
+        Ok(program) => program,
+        Err(_) => panic!("Parse IR failed")
+    };
+
+    assert_eq!(program.variables, vec![variable("alice"), variable("the old king")]);
+    assert_eq!(program.source_map, vec![5, 6, 7, 8, 9].into_iter().map(ir::SourceLocation::Line).collect::<Vec<_>>());
+    assert_eq!(program.instructions, vec![
+        ir::Instruction::Label(BigUint::from(0u32)),
+        ir::Instruction::RandomInstruction(variable("alice"), number(6)),
+        ir::Instruction::IfInstruction(
+            ir::Condition::EqualTo(ir::Expression::Variable("alice".to_string()), ir::Expression::Variable("the old king".to_string())),
+            Box::new(ir::Instruction::GotoInstruction(number(0)))
+        ),
+        ir::Instruction::IfInstruction(ir::Condition::EndOfInput, Box::new(ir::Instruction::ExitInstruction)),
+        ir::Instruction::AddInstruction(variable("the old king"), ir::Expression::Variable("alice".to_string()))
+    ]);
+}
+
+#[test]
+fn ir_parser_reads_back_emitted_ir() {
+    let instructions = vec![
+        ir::Instruction::Label(BigUint::from(0u32)),
+        ir::Instruction::InputNumberInstruction(variable("goto")),
+        ir::Instruction::AssignmentInstruction(variable("42"), ir::Expression::NumberLiteral("123456789012345678901234567890".parse().unwrap())),
+        ir::Instruction::IfInstruction(
+            ir::Condition::LessThan(ir::Expression::Variable("goto".to_string()), number(3)),
+            Box::new(ir::Instruction::SubInstruction(variable("o'brien"), number(1)))
+        ),
+        ir::Instruction::PrintStringInstruction(variable("o'brien")),
+        ir::Instruction::InputInstruction(variable("42")),
+        ir::Instruction::InputCharacterInstruction(variable("goto")),
+        ir::Instruction::PrintNumberInstruction(variable("42")),
+        ir::Instruction::AssignmentInstruction(variable("exit-wound"), ir::Expression::Variable("if-only".to_string())),
+        ir::Instruction::PrintNumberInstruction(variable("print'd"))
+    ];
+    let variables = vec![variable("goto"), variable("42"), variable("o'brien"), variable("exit-wound"), variable("if-only"), variable("print'd")];
+    let program = ir::Program::new(instructions, variables, vec![], ir::Metadata::default());
+    let source = emit::ir_to_string(&program);
+
+    match ir_parser::parse_ir(&source) {
+        Ok(parsed) => assert_eq!((parsed.instructions, parsed.variables), (program.instructions, program.variables)),
+        Err(_) => panic!("Parse IR failed:\n{}", source)
+    }
+}
+
+#[test]
+fn ir_parser_reports_the_line_of_a_syntax_error() {
+    let errors = match ir_parser::parse_ir("var alice\nlabel 0:\n    alice == 3\n") {
+        Ok(_) => panic!("Malformed IR was accepted"),
+        Err(errors) => errors.iter().map(|error| error.get_plain_error()).collect::<Vec<_>>()
+    };
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("Line 3"));
+}
+
+#[test]
+fn ir_parser_reports_undeclared_variables() {
+    let errors = match ir_parser::parse_ir("var alice\nlabel 0:\n    alice = bob\n    print bob\n") {
+        Ok(_) => panic!("Undeclared variable was accepted"),
+        Err(errors) => errors.iter().map(|error| error.get_plain_error()).collect::<Vec<_>>()
+    };
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("\"bob\""));
+}
@@ -0,0 +1,57 @@
+use crate::config::{Config, KeywordsConfig, KeywordCategoryConfig};
+use crate::keyword_defs;
+
+fn extend_config(said: Vec<&str>, negative_adjective: Vec<&str>) -> Config {
+    let extend = |keywords: Vec<&str>| Some(KeywordCategoryConfig {
+        replace: None,
+        extend: keywords.into_iter().map(|keyword| keyword.to_string()).collect()
+    });
+    Config {
+        keywords: KeywordsConfig {
+            said: extend(said),
+            negative_adjective: extend(negative_adjective),
+            ..KeywordsConfig::default()
+        },
+        ..Config::default()
+    }
+}
+
+fn get_keyword_errors(config: &Config) -> Vec<String> {
+    match keyword_defs::get_keyword_defs_from_search_path(&[], config, keyword_defs::DEFAULT_LANGUAGE) {
+        Ok(_) => vec![],
+        Err(errors) => errors.iter().map(|error| error.get_plain_error()).collect()
+    }
+}
+
+#[test]
+fn built_in_language_packs_are_consistent() {
+    for language in ["english", "spanish"] {
+        assert!(keyword_defs::get_language_keyword_defs(language).is_ok(), "{} is inconsistent", language);
+    }
+}
+
+#[test]
+fn adjective_listed_as_positive_and_negative_is_reported() {
+    let errors = get_keyword_errors(&extend_config(vec![], vec!["happy"]));
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("\"happy\""));
+    assert!(errors[0].contains("languages/english/positive_adjective_keywords.txt:"));
+}
+
+#[test]
+fn said_keyword_colliding_with_to_be_keyword_is_reported() {
+    let errors = get_keyword_errors(&extend_config(vec!["is"], vec![]));
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("to_be"));
+    assert!(errors[0].contains("said"));
+}
+
+#[test]
+fn blank_keyword_is_reported() {
+    let errors = get_keyword_errors(&extend_config(vec![" "], vec![]));
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].starts_with("Blank Page Error"));
+}
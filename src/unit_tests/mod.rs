@@ -1,2 +1,24 @@
 #[cfg(test)]
-mod parser_tests;
\ No newline at end of file
+mod parser_tests;
+#[cfg(test)]
+mod config_tests;
+#[cfg(test)]
+mod morphology_tests;
+#[cfg(test)]
+mod keyword_defs_tests;
+#[cfg(test)]
+mod pronoun_tests;
+#[cfg(test)]
+mod formatter_tests;
+#[cfg(test)]
+mod emit_tests;
+#[cfg(test)]
+mod ir_parser_tests;
+#[cfg(test)]
+mod optimizer_tests;
+#[cfg(test)]
+mod cfg_tests;
+#[cfg(test)]
+mod ast_to_ir_tests;
+#[cfg(test)]
+mod verify_tests;
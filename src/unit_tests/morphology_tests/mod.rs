@@ -0,0 +1,56 @@
+use crate::morphology::Morphology;
+use std::collections::HashSet;
+
+fn inflect_english(lemma: &str) -> HashSet<String> {
+    Morphology::English.inflect(&HashSet::from([lemma.to_string()]))
+}
+
+#[test]
+fn english_morphology_inflects_regular_verbs() {
+    let inflections = inflect_english("whisper");
+
+    for form in ["whisper", "whispers", "whispered", "whispering", "had whispered", "will whisper", "was whispering"] {
+        assert!(inflections.contains(form), "missing {}", form);
+    }
+}
+
+#[test]
+fn english_morphology_applies_spelling_rules() {
+    assert!(inflect_english("reply").is_superset(&HashSet::from(["replies".to_string(), "replied".to_string(), "replying".to_string()])));
+    assert!(inflect_english("declare").is_superset(&HashSet::from(["declares".to_string(), "declared".to_string(), "declaring".to_string()])));
+    assert!(inflect_english("confess").is_superset(&HashSet::from(["confesses".to_string(), "confessed".to_string()])));
+}
+
+#[test]
+fn english_morphology_doubles_final_consonants_after_a_stressed_short_vowel() {
+    assert!(inflect_english("stop").is_superset(&HashSet::from(["stops".to_string(), "stopped".to_string(), "stopping".to_string()])));
+    assert!(inflect_english("admit").is_superset(&HashSet::from(["admits".to_string(), "admitted".to_string(), "admitting".to_string()])));
+    assert!(inflect_english("visit").is_superset(&HashSet::from(["visited".to_string(), "visiting".to_string()])));
+    assert!(inflect_english("shout").contains("shouted"));
+    assert!(inflect_english("show").contains("showed"));
+}
+
+#[test]
+fn english_morphology_uses_irregular_verb_list() {
+    let inflections = inflect_english("go to");
+
+    for form in ["go to", "goes to", "went to", "gone to", "going to", "will go to", "has gone to", "is going to"] {
+        assert!(inflections.contains(form), "missing {}", form);
+    }
+    assert!(!inflections.contains("goed to"));
+}
+
+#[test]
+fn english_morphology_does_not_use_be_as_a_present_form() {
+    let inflections = inflect_english("be");
+
+    assert!(inflections.is_superset(&HashSet::from(["is".to_string(), "were".to_string(), "will be".to_string(), "had been".to_string()])));
+    assert!(!inflections.contains("be"));
+}
+
+#[test]
+fn no_morphology_keeps_keywords_unchanged() {
+    let keywords = HashSet::from(["dijo".to_string()]);
+
+    assert_eq!(Morphology::None.inflect(&keywords), keywords);
+}
@@ -0,0 +1,45 @@
+use crate::ir_parser;
+use crate::optimizer;
+use crate::ast_to_ir::ir;
+
+fn optimize(source: &str) -> Vec<String> {
+    let program = match ir_parser::parse_ir(source) {
+        Ok(program) => program,
+        Err(_) => panic!("Parse IR failed")
+    };
+    optimizer::fold_constants(program).instructions.iter().map(ir::Instruction::to_string).collect()
+}
+
+#[test]
+fn optimizer_folds_arithmetic_on_known_values() {
+    let source = "var alice\nvar bob\nlabel 0:\n    alice = 203\n    alice += 8\n    bob = alice\n    bob -= 11\n    print bob\n";
+
+    assert_eq!(optimize(source), vec!["label 0:", "alice = 203", "alice = 211", "bob = 211", "bob = 200", "print bob"]);
+}
+
+#[test]
+fn optimizer_forgets_values_at_labels_and_after_unknown_writes() {
+    let source = "var alice\nvar bob\nlabel 0:\n    alice = 3\n    bob = roll alice\n    bob += 1\n    if bob > 2 alice += 1\n    alice += 1\nlabel 1:\n    alice += 1\n";
+
+    assert_eq!(optimize(source), vec![
+        "label 0:", "alice = 3", "bob = roll 3", "bob += 1", "if bob > 2 alice = 4", "alice += 1",
+        "label 1:", "alice += 1"
+    ]);
+}
+
+#[test]
+fn optimizer_keeps_subtractions_that_would_fall_below_zero() {
+    let source = "var alice\nlabel 0:\n    alice = 2\n    alice -= 3\n    print alice\n";
+
+    assert_eq!(optimize(source), vec!["label 0:", "alice = 2", "alice -= 3", "print alice"]);
+}
+
+#[test]
+fn optimizer_resolves_gotos_and_decided_conditions() {
+    let source = "var alice\nlabel 0:\n    alice = 1\n    if alice == 1 print alice\n    if alice > 1 exit\n    goto alice\nlabel 1:\n    alice = 7\n    goto alice\n";
+
+    assert_eq!(optimize(source), vec![
+        "label 0:", "alice = 1", "print alice", "goto 1",
+        "label 1:", "alice = 7", "goto alice"
+    ]);
+}
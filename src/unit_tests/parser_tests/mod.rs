@@ -1,6 +1,13 @@
-mod parser_test_utils;
+pub mod parser_test_utils;
 use crate::parser::ast;
-use parser_test_utils::parse_program_string;
+use crate::lexer;
+use crate::lexer::lexer_types::LexerToken;
+use chumsky::Parser;
+use parser_test_utils::{parse_program_string, parse_program_string_in_language, parse_program_string_with_warnings, parse_program_string_errors};
+use crate::keyword_defs;
+use crate::parser::keyword_trie::KeywordTrie;
+use std::collections::HashSet;
+use chumsky::prelude::any;
 
 #[test] 
 fn parser_correctly_parses_assignment_statement_with_was() {
@@ -13,7 +20,8 @@ fn parser_correctly_parses_assignment_statement_with_was() {
                 ast::Variable("charlie".to_string()),
                 ast::VariableOrNumberLiteral("a wizard".to_string())
             )
-        ]
+        ],
+        None
     )]));
 }
 
@@ -28,7 +36,8 @@ fn parser_correctly_parses_assignment_statement_with_were() {
                 ast::Variable("the dog and the cat".to_string()),
                 ast::VariableOrNumberLiteral("great company".to_string())
             )
-        ]
+        ],
+        None
     )]));
 }
 
@@ -44,7 +53,8 @@ fn parser_correctly_parses_assignment_statement_with_is() {
                 ast::Variable("ron".to_string()),
                 ast::VariableOrNumberLiteral("here".to_string())
             )
-        ]
+        ],
+        None
     )]));
 }
 
@@ -59,7 +69,8 @@ fn parser_correctly_parses_assignment_statement_with_are() {
                 ast::Variable("percy and annabeth".to_string()),
                 ast::VariableOrNumberLiteral("here".to_string())
             )
-        ]
+        ],
+        None
     )]));
 }
 
@@ -74,7 +85,8 @@ fn parser_correctly_parses_add_statement() {
                 ast::Variable("percy".to_string()),
                 ast::VariableOrNumberLiteral("a friend".to_string())
             )
-        ]
+        ],
+        None
     )]));
 }
 
@@ -89,7 +101,8 @@ fn parser_correctly_parses_sub_statement() {
                 ast::Variable("macbeth".to_string()),
                 ast::VariableOrNumberLiteral("rain".to_string())
             )
-        ]
+        ],
+        None
     )]));
 }
 
@@ -103,7 +116,8 @@ fn parser_correctly_parses_print_number_statement() {
             ast::Statement::PrintNumberStatement(
                 ast::Variable("charlie".to_string())
             )
-        ]
+        ],
+        None
     )]));
 }
 
@@ -117,7 +131,8 @@ fn parser_correctly_parses_print_character_statement() {
             ast::Statement::PrintStringStatement(
                 ast::Variable("charlie".to_string())
             )
-        ]
+        ],
+        None
     )]));
 }
 
@@ -140,7 +155,8 @@ fn parser_correctly_parses_statements_that_end_in_exclamation_marks() {
                 ast::Variable("john".to_string()),
                 ast::VariableOrNumberLiteral("swimming".to_string())
             )
-        ]
+        ],
+        None
     )]));
 }
 
@@ -163,7 +179,8 @@ fn parser_correctly_parses_statements_that_end_in_question_marks() {
                 ast::Variable("john".to_string()),
                 ast::VariableOrNumberLiteral("swimming".to_string())
             )
-        ]
+        ],
+        None
     )]));
 }
 
@@ -178,7 +195,8 @@ fn parser_correctly_parses_input_statements() {
             ast::Statement::InputStatement(
                 ast::Variable("taylor".to_string())
             )
-        ]
+        ],
+        None
     )]));
 }
 
@@ -191,7 +209,8 @@ fn parser_correctly_parses_exit_statements() {
     assert_eq!(ast, ast::Program(vec![ast::Block(
         vec![
             ast::Statement::ExitStatement
-        ]
+        ],
+        None
     )]));
 }
 
@@ -204,7 +223,8 @@ fn parser_correctly_parses_goto_statement() {
     assert_eq!(ast, ast::Program(vec![ast::Block(
         vec![
             ast::Statement::GotoStatement(ast::VariableOrNumberLiteral("heaven".to_string()))
-        ]
+        ],
+        None
     )]));
 }
 
@@ -220,6 +240,426 @@ fn parser_correctly_parses_if_statment() {
                 ast::VariableOrNumberLiteral("the prince".to_string())),
                 Box::new(ast::Statement::GotoStatement(ast::VariableOrNumberLiteral("heaven".to_string())))
             )
-        ]
+        ],
+        None
     )]));
-}
\ No newline at end of file
+}
+#[test]
+fn parser_correctly_parses_paragraph_name_opener() {
+    let program = "At the Palace: Ron is here.";
+    let ast = parse_program_string(program);
+
+    assert_eq!(ast, ast::Program(vec![ast::Block(
+        vec![
+            ast::Statement::AssignmentStatement(
+                ast::Variable("ron".to_string()),
+                ast::VariableOrNumberLiteral("here".to_string())
+            )
+        ],
+        Some("the palace".to_string())
+    )]));
+}
+
+#[test]
+fn parser_correctly_parses_paragraph_name_opener_on_its_own() {
+    let program = "At the Palace:";
+    let ast = parse_program_string(program);
+
+    assert_eq!(ast, ast::Program(vec![ast::Block(vec![], Some("the palace".to_string()))]));
+}
+
+#[test]
+fn parser_correctly_parses_input_number_statements() {
+    let program = "Alice counted the coins in her hand.";
+    let ast = parse_program_string(program);
+
+    assert_eq!(ast, ast::Program(vec![ast::Block(
+        vec![
+            ast::Statement::InputNumberStatement(
+                ast::Variable("alice".to_string())
+            )
+        ],
+        None
+    )]));
+}
+
+#[test]
+fn parser_correctly_parses_input_character_statements() {
+    let program = "Taylor glanced up to the skies beyond, waiting for an answer.";
+    let ast = parse_program_string(program);
+
+    assert_eq!(ast, ast::Program(vec![ast::Block(
+        vec![
+            ast::Statement::InputCharacterStatement(
+                ast::Variable("taylor".to_string())
+            )
+        ],
+        None
+    )]));
+}
+
+#[test]
+fn parser_correctly_parses_end_of_input_condition() {
+    let program = "If the skies fell silent, then go to heaven.";
+    let ast = parse_program_string(program);
+
+    assert_eq!(ast, ast::Program(vec![ast::Block(
+        vec![
+            ast::Statement::IfStatement(
+                ast::Condition::EndOfInput,
+                Box::new(ast::Statement::GotoStatement(ast::VariableOrNumberLiteral("heaven".to_string())))
+            )
+        ],
+        None
+    )]));
+}
+
+#[test]
+fn parser_correctly_parses_random_statement() {
+    let program = "Alice rolled a die with Bob sides.";
+    let ast = parse_program_string(program);
+
+    assert_eq!(ast, ast::Program(vec![ast::Block(
+        vec![
+            ast::Statement::RandomStatement(
+                ast::Variable("alice".to_string()),
+                ast::VariableOrNumberLiteral("bob".to_string())
+            )
+        ],
+        None
+    )]));
+}
+
+#[test]
+fn lexer_keeps_apostrophes_and_hyphens_inside_words() {
+    let lexer_output = lexer::lexer().parse("O’Brien met the bride-to-be's mother.").unwrap();
+
+    assert_eq!(lexer_output.0[0].0, vec![
+        LexerToken::Text("O'Brien".to_string()),
+        LexerToken::Text("met".to_string()),
+        LexerToken::Text("the".to_string()),
+        LexerToken::Text("bride-to-be's".to_string()),
+        LexerToken::Text("mother".to_string()),
+        LexerToken::Period
+    ]);
+}
+
+#[test]
+fn lexer_does_not_join_words_separated_by_spaced_hyphens() {
+    let lexer_output = lexer::lexer().parse("Alice - Bob").unwrap();
+
+    assert_eq!(lexer_output.0[0].0, vec![
+        LexerToken::Text("Alice".to_string()),
+        LexerToken::Unknown('-'),
+        LexerToken::Text("Bob".to_string())
+    ]);
+}
+
+#[test]
+fn parser_correctly_parses_possessive_variable_names() {
+    let program = "Cinderella's stepmother was cruel.";
+    let ast = parse_program_string(program);
+
+    assert_eq!(ast, ast::Program(vec![ast::Block(
+        vec![
+            ast::Statement::AssignmentStatement(
+                ast::Variable("cinderella's stepmother".to_string()),
+                ast::VariableOrNumberLiteral("cruel".to_string())
+            )
+        ],
+        None
+    )]));
+}
+
+#[test]
+fn parser_correctly_parses_names_with_apostrophes() {
+    let program = "\"Top of the morning\" O’Brien said.";
+    let ast = parse_program_string(program);
+
+    assert_eq!(ast, ast::Program(vec![ast::Block(
+        vec![
+            ast::Statement::PrintNumberStatement(
+                ast::Variable("o'brien".to_string())
+            )
+        ],
+        None
+    )]));
+}
+
+#[test]
+fn parser_parses_arithmetic_and_io_statements_identically_in_english_and_spanish() {
+    let english = "Alice was 5. Alice felt as happy as 3. Alice felt as sad as 1. \"Hi\" Alice said. \"Hi\" Alice said happily. Alice looked up to the skies beyond, waiting for an answer. Alice counted the coins. Alice glanced up to the skies beyond, waiting for an answer. Alice rolled a die with 6 sides.";
+    let spanish = "Alice era 5. Alice se sintió tan feliz como 3. Alice se sintió tan triste como 1. \"Hola\" Alice dijo. \"Hola\" Alice dijo alegremente. Alice miró hacia los cielos lejanos, esperando una respuesta. Alice contó las monedas. Alice vislumbró hacia los cielos lejanos, esperando una respuesta. Alice lanzó un dado de 6 caras.";
+
+    assert_eq!(parse_program_string_in_language(spanish, "spanish"), parse_program_string_in_language(english, "english"));
+}
+
+#[test]
+fn parser_parses_control_flow_identically_in_english_and_spanish() {
+    let english = "At home: If Alice was better than 3, then Alice went to home. If Alice was not 3, then Alice went to 12. If the skies fell silent, then the end.";
+    let spanish = "En home: Si Alice era mejor que 3, entonces Alice fue a home. Si Alice era distinto de 3, entonces Alice fue a 12. Si los cielos callaron, entonces fin.";
+    let ast = parse_program_string_in_language(spanish, "spanish");
+
+    assert_eq!(ast, parse_program_string_in_language(english, "english"));
+    assert_eq!(ast, ast::Program(vec![ast::Block(
+        vec![
+            ast::Statement::IfStatement(
+                ast::Condition::GreaterThan(
+                    ast::VariableOrNumberLiteral("alice".to_string()),
+                    ast::VariableOrNumberLiteral("3".to_string())
+                ),
+                Box::new(ast::Statement::GotoStatement(ast::VariableOrNumberLiteral("home".to_string())))
+            ),
+            ast::Statement::IfStatement(
+                ast::Condition::NotEqualTo(
+                    ast::VariableOrNumberLiteral("alice".to_string()),
+                    ast::VariableOrNumberLiteral("3".to_string())
+                ),
+                Box::new(ast::Statement::GotoStatement(ast::VariableOrNumberLiteral("12".to_string())))
+            ),
+            ast::Statement::IfStatement(
+                ast::Condition::EndOfInput,
+                Box::new(ast::Statement::ExitStatement)
+            )
+        ],
+        Some("home".to_string())
+    )]));
+}
+
+#[test]
+fn unknown_language_is_rejected() {
+    assert!(keyword_defs::get_language_keyword_defs("klingon").is_err());
+}
+
+#[test]
+fn parser_accepts_inflected_verb_forms() {
+    let program = "Alice feels as happy as 3. Alice had felt as happy as 3. Alice will go to 12. \"Hi\" Alice has whispered. Alice will be 5.";
+    let ast = parse_program_string(program);
+
+    assert_eq!(ast, ast::Program(vec![ast::Block(
+        vec![
+            ast::Statement::AddStatement(
+                ast::Variable("alice".to_string()),
+                ast::VariableOrNumberLiteral("3".to_string())
+            ),
+            ast::Statement::AddStatement(
+                ast::Variable("alice".to_string()),
+                ast::VariableOrNumberLiteral("3".to_string())
+            ),
+            ast::Statement::GotoStatement(
+                ast::VariableOrNumberLiteral("12".to_string())
+            ),
+            ast::Statement::PrintNumberStatement(
+                ast::Variable("alice".to_string())
+            ),
+            ast::Statement::AssignmentStatement(
+                ast::Variable("alice".to_string()),
+                ast::VariableOrNumberLiteral("5".to_string())
+            )
+        ],
+        None
+    )]));
+}
+
+#[test]
+fn parser_allows_verb_words_that_do_not_start_a_keyword_in_names() {
+    let program = "He would want her to go to 12.";
+    let ast = parse_program_string(program);
+
+    assert_eq!(ast, ast::Program(vec![ast::Block(
+        vec![
+            ast::Statement::GotoStatement(
+                ast::VariableOrNumberLiteral("12".to_string())
+            )
+        ],
+        None
+    )]));
+}
+
+#[test]
+fn lexer_recognizes_pronoun_declarations() {
+    let lexer_output = lexer::lexer().parse("Alice (she/her) (aside)").unwrap();
+
+    assert_eq!(lexer_output.0[0].0, vec![
+        LexerToken::Text("Alice".to_string()),
+        LexerToken::PronounDeclaration(vec!["she".to_string(), "her".to_string()]),
+        LexerToken::Unknown('('),
+        LexerToken::Text("aside".to_string()),
+        LexerToken::Unknown(')')
+    ]);
+}
+
+#[test]
+fn parser_separates_pronoun_declarations_from_statements() {
+    let program = "Alice (She/Her) was 5.";
+    let ast = parse_program_string(program);
+
+    assert_eq!(ast, ast::Program(vec![ast::Block(
+        vec![
+            ast::Statement::PronounDeclaration(
+                ast::Variable("alice".to_string()),
+                vec!["she".to_string(), "her".to_string()]
+            ),
+            ast::Statement::AssignmentStatement(
+                ast::Variable("alice".to_string()),
+                ast::VariableOrNumberLiteral("5".to_string())
+            )
+        ],
+        None
+    )]));
+}
+
+fn text_tokens(words: &str) -> Vec<LexerToken> {
+    words.split(' ').map(|word| LexerToken::Text(word.to_string())).collect()
+}
+
+#[test]
+fn keyword_trie_prefers_the_longest_keyword() {
+    let keywords = HashSet::from(["is".to_string(), "is like".to_string(), "is like a".to_string()]);
+    let parser = KeywordTrie::new(&keywords).parser().then(any().repeated());
+
+    assert_eq!(parser.parse(text_tokens("is like a fox")).unwrap().0, "is like a");
+    assert_eq!(parser.parse(text_tokens("is like the fox")).unwrap().0, "is like");
+    assert_eq!(parser.parse(text_tokens("is a fox")).unwrap().0, "is");
+}
+
+#[test]
+fn keyword_trie_does_not_match_an_incomplete_keyword() {
+    let keywords = HashSet::from(["want to be like".to_string()]);
+
+    assert!(KeywordTrie::new(&keywords).parser().parse(text_tokens("want to be")).is_err());
+}
+
+#[test]
+fn parser_prefers_longer_goto_keyword_over_to_be_prefix() {
+    let ast = parse_program_string("Alice is going to 12.");
+
+    assert_eq!(ast, ast::Program(vec![ast::Block(
+        vec![
+            ast::Statement::GotoStatement(ast::VariableOrNumberLiteral("12".to_string()))
+        ],
+        None
+    )]));
+}
+
+#[test]
+fn parser_prefers_longer_feel_keyword_over_to_be_prefix() {
+    let ast = parse_program_string("Alice was feeling as happy as 3. If Alice was feeling better than 3, then the end.");
+
+    assert_eq!(ast, ast::Program(vec![ast::Block(
+        vec![
+            ast::Statement::AddStatement(
+                ast::Variable("alice".to_string()),
+                ast::VariableOrNumberLiteral("3".to_string())
+            ),
+            ast::Statement::IfStatement(
+                ast::Condition::GreaterThan(
+                    ast::VariableOrNumberLiteral("alice".to_string()),
+                    ast::VariableOrNumberLiteral("3".to_string())
+                ),
+                Box::new(ast::Statement::ExitStatement)
+            )
+        ],
+        None
+    )]));
+}
+
+#[test]
+fn parser_keeps_words_that_only_start_a_multi_word_keyword_in_names() {
+    let ast = parse_program_string("The want to be free was 3.");
+
+    assert_eq!(ast, ast::Program(vec![ast::Block(
+        vec![
+            ast::Statement::AssignmentStatement(
+                ast::Variable("the want to be free".to_string()),
+                ast::VariableOrNumberLiteral("3".to_string())
+            )
+        ],
+        None
+    )]));
+}
+
+#[test]
+fn parser_reports_sentence_with_several_readings() {
+    let (ast, warnings) = parse_program_string_with_warnings("Alice was the end.");
+
+    assert_eq!(ast, ast::Program(vec![ast::Block(
+        vec![
+            ast::Statement::AssignmentStatement(
+                ast::Variable("alice".to_string()),
+                ast::VariableOrNumberLiteral("the end".to_string())
+            )
+        ],
+        None
+    )]));
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("setting alice to the end"));
+    assert!(warnings[0].contains("ending the story"));
+}
+
+#[test]
+fn parser_lets_the_main_clause_settle_an_ambiguous_sentence() {
+    let (ast, warnings) = parse_program_string_with_warnings("Alice was 4, and the story came to an end. In the end, Bob went to the castle.");
+
+    assert_eq!(ast, ast::Program(vec![ast::Block(
+        vec![
+            ast::Statement::AssignmentStatement(
+                ast::Variable("alice".to_string()),
+                ast::VariableOrNumberLiteral("4".to_string())
+            ),
+            ast::Statement::ExitStatement
+        ],
+        None
+    )]));
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn parser_reports_ambiguous_consequence_of_if_statement() {
+    let (_, warnings) = parse_program_string_with_warnings("If Alice was 3, then Bob went to the end.");
+
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("going to the end"));
+}
+
+#[test]
+fn parser_reports_if_statement_without_comparison() {
+    let errors = parse_program_string_errors("Alice was 3. If Alice, then she went to 3.");
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("\"if alice, then she went to 3\""));
+    assert!(errors[0].contains("whether \"alice\""));
+}
+
+#[test]
+fn parser_reports_where_an_if_statement_condition_goes_wrong() {
+    let errors = parse_program_string_errors("If Alice felt better 3, then the end. If Bob was 3, then the end.");
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("whether \"alice felt better 3\""));
+    assert!(errors[0].contains("starts at \"3\""));
+}
+
+#[test]
+fn lexer_treats_whitespace_only_lines_as_paragraph_breaks() {
+    let lexer_output = lexer::lexer().parse("  Alice was 3.\n   \n\t\nBob was 4.\n  ").unwrap();
+
+    assert_eq!(lexer_output.0.len(), 2);
+    assert_eq!(lexer_output.0[1].0[0], LexerToken::Text("Bob".to_string()));
+}
+
+#[test]
+fn parser_keeps_paragraph_and_error_order_when_parsing_in_parallel() {
+    let program = (0..64).map(|i| format!("Alice was {}.", i)).collect::<Vec<_>>().join("\n");
+    let ast = parse_program_string(&program);
+    let expected_blocks = (0..64).map(|i| ast::Block(
+        vec![ast::Statement::AssignmentStatement(ast::Variable("alice".to_string()), ast::VariableOrNumberLiteral(i.to_string()))],
+        None
+    )).collect::<Vec<_>>();
+    assert_eq!(ast, ast::Program(expected_blocks));
+
+    let program = (0..64).map(|i| format!("If Alice {}, then the end.", i)).collect::<Vec<_>>().join("\n");
+    let errors = parse_program_string_errors(&program);
+    assert_eq!(errors.len(), 64);
+    errors.iter().enumerate().for_each(|(i, error)| assert!(error.contains(&format!("whether \"alice {}\"", i))));
+}
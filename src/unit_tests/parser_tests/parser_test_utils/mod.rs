@@ -2,15 +2,47 @@ use crate::parser;
 use parser::ast;
 use crate::lexer;
 use crate::preprocessor;
-use crate::keyword_defs;
+use crate::keyword_defs::{self, KeywordDefs};
+use crate::variable_extractor;
+use crate::ast_to_ir::{self, ir};
 use chumsky::prelude::*;
 
-pub fn parse_program_string(program_string: &str) -> ast::Program {
+pub fn try_parse_program_string(program_string: &str, keywords: &KeywordDefs) -> Result<(ast::Program, Vec<String>), Vec<String>> {
     let lexer_output = preprocessor::preprocess(lexer::lexer().parse(program_string).unwrap());
-    let keywords = keyword_defs::get_keyword_defs();
-    let ast = match parser::parse_program(lexer_output, keywords) {
-        Ok(ast) => ast,
+    parser::parse_program_with_warnings(lexer_output, keywords)
+        .map(|(ast, warnings)| (ast, warnings.iter().map(|warning| warning.get_plain_warning()).collect()))
+        .map_err(|errors| errors.iter().map(|error| error.get_plain_error()).collect())
+}
+pub fn parse_program_string(program_string: &str) -> ast::Program {
+    parse_program_string_with_warnings(program_string).0
+}
+pub fn parse_program_string_in_language(program_string: &str, language: &str) -> ast::Program {
+    let keywords = match keyword_defs::get_language_keyword_defs(language) {
+        Ok(keywords) => keywords,
+        Err(_) => { panic!("Unknown language") }
+    };
+    match try_parse_program_string(program_string, &keywords) {
+        Ok((ast, _)) => ast,
+        Err(_) => { panic!("Parse program failed") }
+    }
+}
+pub fn parse_program_string_with_warnings(program_string: &str) -> (ast::Program, Vec<String>) {
+    match try_parse_program_string(program_string, &keyword_defs::get_default_keyword_defs()) {
+        Ok(parsed) => parsed,
+        Err(_) => { panic!("Parse program failed") }
+    }
+}
+pub fn parse_program_string_errors(program_string: &str) -> Vec<String> {
+    try_parse_program_string(program_string, &keyword_defs::get_default_keyword_defs()).err().unwrap_or_default()
+}
+pub fn convert_program_string(program_string: &str) -> Result<ir::Program, Vec<String>> {
+    let keywords = keyword_defs::get_default_keyword_defs();
+    let ast = match try_parse_program_string(program_string, &keywords) {
+        Ok((ast, _)) => ast,
         Err(_) => { panic!("Parse program failed") }
     };
-    ast
-}
\ No newline at end of file
+    let variables = variable_extractor::get_variables(&ast);
+    ast_to_ir::convert_ast_to_ir_with_warnings(ast, &variables, &keywords.pronouns)
+        .map(|(program, _)| program)
+        .map_err(|errors| errors.iter().map(|error| error.get_plain_error()).collect())
+}
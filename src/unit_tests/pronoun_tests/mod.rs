@@ -0,0 +1,72 @@
+use crate::parser::ast;
+use crate::keyword_defs;
+use crate::variable_extractor;
+use crate::ast_to_ir::pronoun_replacer;
+use super::parser_tests::parser_test_utils::parse_program_string;
+
+fn replace_pronouns_in_program_string(program_string: &str) -> Result<(Vec<ast::Statement>, Vec<String>), Vec<String>> {
+    let keywords = keyword_defs::get_default_keyword_defs();
+    let ast = parse_program_string(program_string);
+    let variables = variable_extractor::get_variables(&ast);
+    let (program, warnings) = pronoun_replacer::replace_pronouns(&ast, &variables, &keywords.pronouns)
+        .map_err(|errors| errors.iter().map(|error| error.get_plain_error()).collect::<Vec<_>>())?;
+    let statements = program.0.into_iter()
+        .flat_map(|block| block.0)
+        .filter(|statement| !matches!(statement, ast::Statement::PronounDeclaration(_, _)))
+        .collect();
+    Ok((statements, warnings.iter().map(|warning| warning.get_plain_warning()).collect()))
+}
+
+fn add_statement(lhs: &str, rhs: &str) -> ast::Statement {
+    ast::Statement::AddStatement(ast::Variable(lhs.to_string()), ast::VariableOrNumberLiteral(rhs.to_string()))
+}
+
+#[test]
+fn declared_pronoun_refers_to_the_character_that_declared_it() {
+    let (statements, warnings) = replace_pronouns_in_program_string("Alice (she/her) was 5. Bob (he/him) was 3. She felt as happy as him.").unwrap();
+
+    assert_eq!(statements[2], add_statement("alice", "bob"));
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn declaration_in_a_comment_binds_the_longest_matching_character_name() {
+    let (statements, _) = replace_pronouns_in_program_string("The brave Alice (she/her) arrived at dawn. Bob was 3. Alice was 5. Bob was 2. She felt as happy as 1.").unwrap();
+
+    assert_eq!(statements[4], add_statement("alice", "1"));
+}
+
+#[test]
+fn undeclared_pronoun_falls_back_to_most_recent_undeclared_character() {
+    let (statements, _) = replace_pronouns_in_program_string("Carol was 3. Alice (she/her) was 5. They felt as happy as 1.").unwrap();
+
+    assert_eq!(statements[2], add_statement("carol", "1"));
+}
+
+#[test]
+fn pronoun_shared_by_characters_in_the_same_paragraph_is_reported() {
+    let (statements, warnings) = replace_pronouns_in_program_string("Alice (she/her) was 5. Carol (she/her) was 3. She felt as happy as 1.").unwrap();
+
+    assert_eq!(statements[2], add_statement("carol", "1"));
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("\"she\""));
+}
+
+#[test]
+fn pronoun_shared_by_characters_in_different_paragraphs_is_not_reported() {
+    let (statements, warnings) = replace_pronouns_in_program_string("Alice (she/her) was 5.\nCarol (she/her) was 3. She felt as happy as 1.").unwrap();
+
+    assert_eq!(statements[2], add_statement("carol", "1"));
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn pronoun_without_antecedent_is_reported() {
+    let errors = match replace_pronouns_in_program_string("She felt as happy as 1.") {
+        Ok(_) => panic!("Expected a lonely pronoun error"),
+        Err(errors) => errors
+    };
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].starts_with("Lonely Pronoun Error"));
+}
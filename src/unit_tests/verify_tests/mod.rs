@@ -0,0 +1,69 @@
+use crate::ast_to_ir::ir::{self, verify};
+use num::BigUint;
+
+fn variable(name: &str) -> ir::Variable {
+    ir::Variable(name.to_string())
+}
+
+fn label(value: u32) -> ir::Instruction {
+    ir::Instruction::Label(BigUint::from(value))
+}
+
+fn verify_errors(instructions: &[ir::Instruction], variables: &[&str]) -> Vec<String> {
+    let variables = variables.iter().map(|name| variable(name)).collect();
+    let source_map = (0..instructions.len()).map(|line| ir::SourceLocation::Line(line + 1)).collect();
+    let program = ir::Program::new(instructions.to_vec(), variables, source_map, ir::Metadata::default());
+    match verify::verify(&program) {
+        Ok(()) => vec![],
+        Err(errors) => errors.iter().map(|error| error.get_plain_error()).collect()
+    }
+}
+
+#[test]
+fn verifier_accepts_well_formed_ir() {
+    let instructions = vec![
+        label(0),
+        ir::Instruction::InputNumberInstruction(variable("alice")),
+        ir::Instruction::IfInstruction(
+            ir::Condition::GreaterThan(ir::Expression::Variable("alice".to_string()), ir::Expression::NumberLiteral(BigUint::from(3u32))),
+            Box::new(ir::Instruction::GotoInstruction(ir::Expression::NumberLiteral(BigUint::from(0u32))))
+        )
+    ];
+
+    assert!(verify_errors(&instructions, &["alice"]).is_empty());
+}
+
+#[test]
+fn verifier_reports_duplicate_and_nested_labels() {
+    let instructions = vec![
+        label(0),
+        label(0),
+        ir::Instruction::IfInstruction(ir::Condition::EndOfInput, Box::new(label(1)))
+    ];
+
+    assert_eq!(verify_errors(&instructions, &[]), vec![
+        "Torn Page Error\nAt line 2 of the manuscript, the instruction \"label 0:\" repeats label 0, which was already placed at line 1. The story cannot be bound with a torn page; if the manuscript was not written by hand, this is a bug in the storyteller.".to_string(),
+        "Torn Page Error\nAt line 3 of the manuscript, the instruction \"if eof label 1:\" hides label 1 inside a condition. The story cannot be bound with a torn page; if the manuscript was not written by hand, this is a bug in the storyteller.".to_string()
+    ]);
+}
+
+#[test]
+fn verifier_reports_unknown_and_nameless_characters() {
+    let instructions = vec![
+        label(0),
+        ir::Instruction::AssignmentInstruction(variable("alice"), ir::Expression::Variable("bob".to_string())),
+        ir::Instruction::PrintNumberInstruction(variable(""))
+    ];
+    let errors = verify_errors(&instructions, &["alice"]);
+
+    assert_eq!(errors.len(), 2);
+    assert!(errors[0].contains("\"alice = bob\" uses \"bob\", who is missing from the declared characters."));
+    assert!(errors[1].contains("\"print \"\"\" uses a character without a name."));
+}
+
+#[test]
+fn verifier_reports_jumps_to_missing_labels() {
+    let instructions = vec![label(0), ir::Instruction::GotoInstruction(ir::Expression::NumberLiteral(BigUint::from(7u32)))];
+
+    assert!(verify_errors(&instructions, &[])[0].contains("\"goto 7\" jumps to label 7, which does not exist."));
+}
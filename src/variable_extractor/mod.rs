@@ -16,6 +16,9 @@ pub fn get_variables(ast: &ast::Program) -> HashSet<ir::Variable> {
                 ast::Statement::SubStatement(lhs, _) => {
                     variables.insert(ir::Variable(lhs.0.clone()));
                 }
+                ast::Statement::RandomStatement(lhs, _) => {
+                    variables.insert(ir::Variable(lhs.0.clone()));
+                }
                 ast::Statement::PrintNumberStatement(variable) => {
                     variables.insert(ir::Variable(variable.0.clone()));
                 }
@@ -25,8 +28,14 @@ pub fn get_variables(ast: &ast::Program) -> HashSet<ir::Variable> {
                 ast::Statement::InputStatement(variable) => {
                     variables.insert(ir::Variable(variable.0.clone()));
                 }
+                ast::Statement::InputNumberStatement(variable) => {
+                    variables.insert(ir::Variable(variable.0.clone()));
+                }
+                ast::Statement::InputCharacterStatement(variable) => {
+                    variables.insert(ir::Variable(variable.0.clone()));
+                }
                 ast::Statement::IfStatement(_, statement) => {
-                    let variables_in_statement = get_variables(&ast::Program(vec!(ast::Block(vec!((**statement).clone())))));
+                    let variables_in_statement = get_variables(&ast::Program(vec!(ast::Block(vec!((**statement).clone()), None))));
                     for variable in variables_in_statement {
                         variables.insert(variable);
                     };
@@ -1,7 +1,8 @@
 use utils::copy_directory_structure;
+use storyteller::options::Options;
 use walkdir::WalkDir;
 use std::ffi::OsStr;
-use std::io::Cursor;
+use std::io::{Cursor, Write};
 use std::process::Command;
 use std::path::Path;
 use std::process::Stdio;
@@ -10,16 +11,96 @@ mod utils;
 
 #[test]
 fn test_interpreter() {
+    run_interpreter_tests(None);
+}
+
+#[test]
+fn test_optimized_interpreter() {
+    run_interpreter_tests(Some(true));
+}
+
+#[test]
+fn test_compiler() {
+    run_compiler_tests(None, "tests_output");
+}
+
+#[test]
+fn test_optimized_compiler() {
+    run_compiler_tests(Some(true), "tests_output_optimized");
+}
+
+#[test]
+fn test_compiler_output_is_reproducible() {
+    let output_directory_path = Path::new("tests_output_reproducible");
+    if Path::try_exists(output_directory_path).unwrap() {
+        fs::remove_dir_all(output_directory_path).unwrap();
+    }
+    fs::create_dir(output_directory_path).unwrap();
+    for entry in WalkDir::new("examples").into_iter().filter_map(|e| e.ok()) {
+        let path = entry.path().to_path_buf();
+        if path.is_file() && matches!(path.extension().and_then(OsStr::to_str), Some("story") | Some("stir")) {
+            let c_paths = ["first.c", "second.c"].map(|file_name| output_directory_path.join(file_name));
+            for c_path in &c_paths {
+                storyteller::compile(path.to_str().unwrap().to_string(), c_path.to_str().unwrap().to_string());
+            }
+            assert_eq!(fs::read(&c_paths[0]).unwrap(), fs::read(&c_paths[1]).unwrap(), "Failed: {}", path.to_str().unwrap());
+            println!("Passed Reproducibility Test: {}", path.to_str().unwrap());
+        }
+    }
+}
+
+#[test]
+fn test_number_input_beyond_the_limit_is_rejected() {
+    let output_directory_path = Path::new("tests_output_number_limit");
+    if Path::try_exists(output_directory_path).unwrap() {
+        fs::remove_dir_all(output_directory_path).unwrap();
+    }
+    fs::create_dir(output_directory_path).unwrap();
+    let story_path = "examples/io/input_number.story";
+    let c_path = output_directory_path.join("input_number.c");
+    let executable_path = output_directory_path.join("input_number");
+    storyteller::compile(story_path.to_string(), c_path.to_str().unwrap().to_string());
+    Command::new("gcc")
+        .arg(c_path.to_str().unwrap())
+        .arg("-o")
+        .arg(executable_path.to_str().unwrap())
+        .output()
+        .expect("Failed to compile");
+
+    let expected_error = storyteller::errors::runtime_errors::number_input_error().get_plain_error();
+    let mut interpreter = Command::new(env!("CARGO_BIN_EXE_storyteller"));
+    interpreter.arg(story_path);
+    for mut command in [interpreter, Command::new(executable_path.to_str().unwrap())] {
+        let mut process = command.stdin(Stdio::piped()).stdout(Stdio::piped()).spawn().expect("Failed to execute");
+        let _ = process.stdin.take().unwrap().write_all(b"9223372036854775808\n");
+        let output = process.wait_with_output().expect("Failed to execute");
+        assert!(!output.status.success(), "Accepted a number beyond the limit: {:?}", command.get_program());
+        assert!(String::from_utf8(output.stdout).unwrap().contains(&expected_error), "Failed: {:?}", command.get_program());
+    }
+
+    let mut interpreter = Command::new(env!("CARGO_BIN_EXE_storyteller"));
+    interpreter.arg(story_path);
+    for mut command in [interpreter, Command::new(executable_path.to_str().unwrap())] {
+        let mut process = command.stdin(Stdio::piped()).stdout(Stdio::piped()).spawn().expect("Failed to execute");
+        let _ = process.stdin.take().unwrap().write_all(b"3000000000\n");
+        let output = process.wait_with_output().expect("Failed to execute");
+        assert_eq!(String::from_utf8(output.stdout).unwrap(), "3000000016", "Failed: {:?}", command.get_program());
+    }
+}
+
+fn run_interpreter_tests(optimize: Option<bool>) {
     let directory_path = "examples";
     for entry in WalkDir::new(directory_path).into_iter().filter_map(|e| e.ok()) {
         let path = entry.path().to_path_buf();
-        if path.is_file() && path.extension() == Some(OsStr::new("story")) {
+        if path.is_file() && matches!(path.extension().and_then(OsStr::to_str), Some("story") | Some("stir")) {
             let mut input_output_path = path.clone();
             input_output_path.set_extension("txt");
             let (input, expected_output) = utils::extract_input_and_output(&input_output_path);
+            let options = Options { seed: utils::extract_seed(&input_output_path), optimize, ..Options::default() };
             let mut actual_output = Cursor::new(vec![]);
-            storyteller::interpret(
+            storyteller::interpret_with_options(
                 path.to_str().unwrap().to_string(), 
+                &options,
                 &mut Cursor::new(input.as_bytes()),
                 &mut actual_output
             );
@@ -29,11 +110,9 @@ fn test_interpreter() {
     }
 }
 
-#[test]
-fn test_compiler() {
+fn run_compiler_tests(optimize: Option<bool>, examples_compiler_output_directory: &str) {
     let examples_directory = "examples";
     let examples_directory_path = Path::new(examples_directory);
-    let examples_compiler_output_directory = "tests_output";
     let examples_compiler_output_directory_path = Path::new(examples_compiler_output_directory);
     if Path::try_exists(examples_compiler_output_directory_path).unwrap() {
         fs::remove_dir_all(examples_compiler_output_directory_path).unwrap();
@@ -43,16 +122,20 @@ fn test_compiler() {
 
     for entry in WalkDir::new(examples_directory).into_iter().filter_map(|e| e.ok()) {
         let path = entry.path().to_path_buf();
-        if path.is_file() && path.extension() == Some(OsStr::new("story")) {
+        if path.is_file() && matches!(path.extension().and_then(OsStr::to_str), Some("story") | Some("stir")) {
             let mut compiled_c_path = path.clone();
             compiled_c_path.set_extension("c");
             compiled_c_path = utils::change_root(compiled_c_path, examples_compiler_output_directory.to_string());
             let mut executable_path = path.clone();
             executable_path.set_extension("");
             executable_path = utils::change_root(executable_path, examples_compiler_output_directory.to_string());
-            storyteller::compile(
+            let mut input_output_path = path.clone();
+            input_output_path.set_extension("txt");
+            let options = Options { seed: utils::extract_seed(&input_output_path), optimize, ..Options::default() };
+            storyteller::compile_with_options(
                 path.to_str().unwrap().to_string(),
-                compiled_c_path.to_str().unwrap().to_string()
+                compiled_c_path.to_str().unwrap().to_string(),
+                &options
             );
 
             Command::new("gcc")
@@ -62,21 +145,15 @@ fn test_compiler() {
                 .output()
                 .expect("Failed to compile");
 
-            let mut input_output_path = path.clone();
-            input_output_path.set_extension("txt");
             let (input, expected_output) = utils::extract_input_and_output(&input_output_path);
-            let echo_input = Command::new("echo")
-                .arg(input)
+            let mut executable = Command::new(executable_path.to_str().unwrap())
+                .stdin(Stdio::piped())
                 .stdout(Stdio::piped())
                 .spawn()
                 .expect("Failed to execute");
+            let _ = executable.stdin.take().unwrap().write_all(input.as_bytes());
+            let actual_output = executable.wait_with_output().expect("Failed to execute").stdout;
 
-            let actual_output = Command::new(executable_path.to_str().unwrap())
-                .stdin(Stdio::from(echo_input.stdout.unwrap()))
-                .output()
-                .expect("Failed to execute")
-                .stdout;
-    
             assert_eq!(String::from_utf8(actual_output).unwrap(), expected_output, "Failed: {}", path.to_str().unwrap());
             println!("Passed Compiler Test: {}", path.to_str().unwrap());
         }
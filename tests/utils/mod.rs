@@ -6,28 +6,38 @@ pub fn extract_input_and_output(path: &PathBuf) -> (String, String) {
     enum State {
         Input,
         Output,
+        Seed,
         None,
     }
-    let contents = std::fs::read_to_string(path).expect(format!("File {} not found", path.to_str().unwrap()).as_str());
+    let contents = std::fs::read_to_string(path).unwrap_or_else(|_| panic!("File {} not found", path.to_str().unwrap()));
     let mut input_lines = vec![];
     let mut output_lines = vec![];
     let mut state = State::None;
-    contents.split("\n").into_iter().for_each(|line| {
+    contents.split("\n").for_each(|line| {
         if line.eq("Input:") {
             state = State::Input;
         } else if line.eq("Output:") {
             state = State::Output;
+        } else if line.eq("Seed:") {
+            state = State::Seed;
         } else {
             match state {
                 State::Input => input_lines.push(line),
                 State::Output => output_lines.push(line),
-                State::None => (),
+                State::Seed | State::None => (),
             }
         }
     });
     (input_lines.join("\n"), output_lines.join("\n"))
 }
 
+pub fn extract_seed(path: &PathBuf) -> Option<u64> {
+    let contents = std::fs::read_to_string(path).unwrap_or_else(|_| panic!("File {} not found", path.to_str().unwrap()));
+    let mut lines = contents.split("\n");
+    lines.find(|line| line.eq(&"Seed:"))?;
+    Some(lines.next()?.parse().expect("Seed must be a number"))
+}
+
 pub fn change_root(path: PathBuf, new_root: String) -> PathBuf {
     let mut path_components: Vec<_> = path.components().collect();
     let new_root_osstring = OsString::from(new_root);